@@ -6,8 +6,8 @@
 //!
 //! Run with: cargo bench
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use fast_base_convert::{convert_base_baseline, convert_base};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use fast_base_convert::{convert_base_baseline, convert_base, convert_base_in_place, convert_base_unchecked, convert_base_with_options, divide_conquer, ConvertOptions, Converter};
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 
@@ -193,7 +193,7 @@ fn bench_different_bases(c: &mut Criterion) {
         input.iter_mut().for_each(|d| *d %= from);
 
         group.bench_with_input(
-            &format!("{}_to_{}", from, to),
+            format!("{}_to_{}", from, to),
             &(input, from, to),
             |b, (input, from, to)| {
                 b.iter(|| {
@@ -216,7 +216,7 @@ fn bench_memory_allocation(c: &mut Criterion) {
         let input = generate_random_digits(&mut rng, *size, 10);
 
         group.bench_with_input(
-            &format!("size_{}", size),
+            format!("size_{}", size),
             &input,
             |b, input| {
                 b.iter(|| {
@@ -229,13 +229,314 @@ fn bench_memory_allocation(c: &mut Criterion) {
     group.finish();
 }
 
+/// Compare a reused Converter against the free function in a hot loop
+fn bench_converter_reuse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("converter_reuse");
+    group.sample_size(10);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let inputs: Vec<Vec<u64>> = (0..100_000)
+        .map(|_| generate_random_digits(&mut rng, 5, 10))
+        .collect();
+
+    group.bench_function("free_function_100k_5_digits", |b| {
+        b.iter(|| {
+            for input in &inputs {
+                black_box(convert_base(black_box(input), 10, 7));
+            }
+        })
+    });
+
+    group.bench_function("converter_100k_5_digits", |b| {
+        let mut converter = Converter::new();
+        b.iter(|| {
+            for input in &inputs {
+                black_box(converter.convert(black_box(input), 10, 7));
+            }
+        })
+    });
+
+    // 5-digit inputs take the u128 fast path, which never touches the
+    // scratch buffers, so also compare on inputs that reach the general loop
+    let large_inputs: Vec<Vec<u64>> = (0..1_000)
+        .map(|_| generate_random_digits(&mut rng, 100, 10))
+        .collect();
+
+    group.bench_function("free_function_1k_100_digits", |b| {
+        b.iter(|| {
+            for input in &large_inputs {
+                black_box(convert_base(black_box(input), 10, 7));
+            }
+        })
+    });
+
+    group.bench_function("converter_1k_100_digits", |b| {
+        let mut converter = Converter::new();
+        b.iter(|| {
+            for input in &large_inputs {
+                black_box(converter.convert(black_box(input), 10, 7));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// Compare divide-and-conquer against repeated division on 10^10000
+fn bench_divide_and_conquer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("divide_and_conquer");
+    group.sample_size(10);
+
+    // Least-significant digit first, so the leading 1 goes last
+    let mut input = vec![0; 10001];
+    input[10000] = 1;
+
+    // The chunked repeated-division loop, reached by disabling
+    // divide-and-conquer. Decimal input is still packed into base 10^9
+    // first, so the loop works on 1112 limbs
+    let chunked = ConvertOptions {
+        divide_and_conquer_threshold: usize::MAX,
+        chunked_threshold: 0,
+        ..ConvertOptions::default()
+    };
+    group.bench_function("chunked_10_pow_10000_10_to_2", |b| {
+        b.iter(|| {
+            convert_base_with_options(black_box(&input), black_box(10), black_box(2), &chunked)
+        })
+    });
+
+    group.bench_function("divide_conquer_10_pow_10000_10_to_2", |b| {
+        b.iter(|| {
+            divide_conquer::convert_base(black_box(&input), black_box(10), black_box(2))
+        })
+    });
+
+    group.finish();
+}
+
+/// 50 decimal digits overflow u128 and land in the 256-bit tier
+fn bench_medium_numbers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("medium_numbers");
+
+    let input: Vec<u64> = (0..50).map(|i| (i * 7 + 3) % 10).collect();
+
+    group.bench_function("baseline_50_digits_10_to_16", |b| {
+        b.iter(|| {
+            convert_base_baseline(black_box(&input), black_box(10), black_box(16))
+        })
+    });
+
+    group.bench_function("optimized_50_digits_10_to_16", |b| {
+        b.iter(|| {
+            convert_base(black_box(&input), black_box(10), black_box(16))
+        })
+    });
+
+    group.finish();
+}
+
+/// Look for a size below which the baseline beats the optimized dispatch
+fn bench_tiny_inputs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tiny_inputs");
+
+    for (from_base, to_base) in [(10, 16), (2, 16), (1000, 7)] {
+        for len in [1, 2, 4, 8, 16] {
+            let input: Vec<u64> = (0..len).map(|i| (i * 7 + 3) % from_base).collect();
+
+            group.bench_function(format!("baseline_{}_to_{}_len_{}", from_base, to_base, len), |b| {
+                b.iter(|| convert_base_baseline(black_box(&input), black_box(from_base), black_box(to_base)))
+            });
+            group.bench_function(format!("optimized_{}_to_{}_len_{}", from_base, to_base, len), |b| {
+                b.iter(|| convert_base(black_box(&input), black_box(from_base), black_box(to_base)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Cost of the per-digit validation scan on small inputs
+fn bench_unchecked(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unchecked");
+
+    for (from_base, to_base) in [(10, 16), (1000, 7), (2, 16)] {
+        let input: Vec<u64> = (0..5).map(|i| (i * 7 + 3) % from_base).collect();
+
+        group.bench_function(format!("checked_{}_to_{}_len_5", from_base, to_base), |b| {
+            b.iter(|| convert_base(black_box(&input), black_box(from_base), black_box(to_base)))
+        });
+        group.bench_function(format!("unchecked_{}_to_{}_len_5", from_base, to_base), |b| {
+            b.iter(|| convert_base_unchecked(black_box(&input), black_box(from_base), black_box(to_base)))
+        });
+    }
+
+    group.finish();
+}
+
+/// 10^10000 to and from hex, where decimal is packed into base 10^9 limbs
+fn bench_decimal_super_digits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decimal_super_digits");
+    group.sample_size(10);
+
+    let mut decimal = vec![0u64; 10_001];
+    decimal[10_000] = 1;
+    let hex = convert_base(&decimal, 10, 16);
+
+    group.bench_function("divide_conquer_10_to_16", |b| {
+        b.iter(|| divide_conquer::convert_base(black_box(&decimal), black_box(10), black_box(16)))
+    });
+    group.bench_function("super_digits_10_to_16", |b| {
+        b.iter(|| convert_base(black_box(&decimal), black_box(10), black_box(16)))
+    });
+    group.bench_function("divide_conquer_16_to_10", |b| {
+        b.iter(|| divide_conquer::convert_base(black_box(&hex), black_box(16), black_box(10)))
+    });
+    group.bench_function("super_digits_16_to_10", |b| {
+        b.iter(|| convert_base(black_box(&hex), black_box(16), black_box(10)))
+    });
+
+    group.finish();
+}
+
+/// Repeated division by a constant to_base, where the reciprocal applies
+fn bench_division_by_constant(c: &mut Criterion) {
+    let mut group = c.benchmark_group("division_by_constant");
+
+    // 10^1000 is long enough for divide and conquer; the 60-limb inputs
+    // stay on the repeated-division loop
+    let mut power = vec![0u64; 1001];
+    power[1000] = 1;
+    let small_bases: Vec<u64> = (0..60).map(|i| (i * 7919 + 13) % 1000).collect();
+    let large_bases: Vec<u64> = (0..60).map(|i| (i * 7919 + 13) % 4_294_967_291).collect();
+
+    group.bench_function("pow10_1000_to_7", |b| {
+        b.iter(|| convert_base(black_box(&power), black_box(10), black_box(7)))
+    });
+    group.bench_function("60_limbs_1000_to_7", |b| {
+        b.iter(|| convert_base(black_box(&small_bases), black_box(1000), black_box(7)))
+    });
+    group.bench_function("60_limbs_4294967291_to_1000003", |b| {
+        b.iter(|| convert_base(black_box(&large_bases), black_box(4_294_967_291), black_box(1_000_003)))
+    });
+
+    group.finish();
+}
+
+/// Base 10 to its cube: grouping three digits at a time vs repeated division
+fn bench_power_of_self(c: &mut Criterion) {
+    let mut group = c.benchmark_group("power_of_self");
+
+    let input: Vec<u64> = (0..1000).map(|i| (i * 7 + 3) % 10).collect();
+
+    group.bench_function("baseline_1000_digits_10_to_1000", |b| {
+        b.iter(|| convert_base_baseline(black_box(&input), black_box(10), black_box(1000)))
+    });
+
+    group.bench_function("divide_conquer_1000_digits_10_to_1000", |b| {
+        b.iter(|| divide_conquer::convert_base(black_box(&input), black_box(10), black_box(1000)))
+    });
+
+    group.bench_function("optimized_1000_digits_10_to_1000", |b| {
+        b.iter(|| convert_base(black_box(&input), black_box(10), black_box(1000)))
+    });
+
+    group.finish();
+}
+
+/// Base 6 to 36 = 6^2 on a large input: aligned grouping vs divide-and-conquer
+fn bench_composite_aligned(c: &mut Criterion) {
+    let mut group = c.benchmark_group("composite_aligned");
+    group.sample_size(10);
+
+    let input: Vec<u64> = (0..10000).map(|i| (i * 7 + 3) % 6).collect();
+
+    group.bench_function("divide_conquer_10000_digits_6_to_36", |b| {
+        b.iter(|| divide_conquer::convert_base(black_box(&input), black_box(6), black_box(36)))
+    });
+
+    group.bench_function("optimized_10000_digits_6_to_36", |b| {
+        b.iter(|| convert_base(black_box(&input), black_box(6), black_box(36)))
+    });
+
+    group.finish();
+}
+
+/// In-place power-of-two conversion: binary to hex shrinks and reuses the
+/// buffer, hex to binary grows and has to allocate
+fn bench_in_place_power_of_two(c: &mut Criterion) {
+    let mut group = c.benchmark_group("in_place_power_of_two");
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let binary = generate_random_digits(&mut rng, 100_000, 2);
+    let hex = convert_base(&binary, 2, 16);
+
+    for (name, input, from_base, to_base) in [("2_to_16", &binary, 2, 16), ("16_to_2", &hex, 16, 2)] {
+        // Both sides clone the input in setup so only the conversion is timed
+        group.bench_function(format!("convert_base_{}", name), |b| {
+            b.iter_batched(
+                || input.clone(),
+                |digits| convert_base(black_box(&digits), black_box(from_base), black_box(to_base)),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_function(format!("in_place_{}", name), |b| {
+            b.iter_batched(
+                || input.clone(),
+                |mut digits| {
+                    convert_base_in_place(black_box(&mut digits), black_box(from_base), black_box(to_base));
+                    digits
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+/// How the chunked repeated-division loop scales against divide-and-conquer
+///
+/// The chunked loop is only reachable with divide-and-conquer disabled.
+/// Doubling the input roughly quadruples its time, as for any repeated
+/// division.
+fn bench_chunked_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chunked_scaling");
+    group.sample_size(10);
+
+    let chunked = ConvertOptions { divide_and_conquer_threshold: usize::MAX, ..ConvertOptions::default() };
+    let mut rng = StdRng::seed_from_u64(42);
+    for size in [2500, 5000, 10000] {
+        let input = generate_random_digits(&mut rng, size, 1000);
+
+        group.bench_function(format!("chunked_{}_digits_1000_to_7", size), |b| {
+            b.iter(|| convert_base_with_options(black_box(&input), black_box(1000), black_box(7), &chunked))
+        });
+        group.bench_function(format!("divide_conquer_{}_digits_1000_to_7", size), |b| {
+            b.iter(|| convert_base(black_box(&input), black_box(1000), black_box(7)))
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_baseline,
     bench_optimized,
     bench_comparison,
     bench_different_bases,
-    bench_memory_allocation
+    bench_memory_allocation,
+    bench_converter_reuse,
+    bench_divide_and_conquer,
+    bench_medium_numbers,
+    bench_tiny_inputs,
+    bench_unchecked,
+    bench_power_of_self,
+    bench_composite_aligned,
+    bench_decimal_super_digits,
+    bench_division_by_constant,
+    bench_in_place_power_of_two,
+    bench_chunked_scaling
 );
 
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,40 @@
+/*
+ * C interface to fast_base_convert, built with the `cffi` feature.
+ *
+ * Digits are least significant first. The caller owns every buffer; size
+ * the output with fbc_estimate_len before calling fbc_convert.
+ */
+#ifndef FAST_BASE_CONVERT_H
+#define FAST_BASE_CONVERT_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define FBC_OK 0
+#define FBC_ERR_NULL_POINTER (-1)
+#define FBC_ERR_BASE_OUT_OF_RANGE (-2)
+#define FBC_ERR_INVALID_DIGIT (-3)
+#define FBC_ERR_BUFFER_TOO_SMALL (-4)
+
+/*
+ * Convert len digits from from_base to to_base, both in 2..=2^32.
+ *
+ * Returns FBC_OK and sets *out_len to the number of digits written to out.
+ * If out_cap is too small, returns FBC_ERR_BUFFER_TOO_SMALL, writes nothing
+ * to out and sets *out_len to the capacity needed.
+ */
+int fbc_convert(const uint64_t *digits, size_t len, uint64_t from_base, uint64_t to_base,
+                uint64_t *out, size_t out_cap, size_t *out_len);
+
+/* An out_cap that is always enough for fbc_convert, or 0 for a bad base. */
+size_t fbc_estimate_len(size_t len, uint64_t from_base, uint64_t to_base);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FAST_BASE_CONVERT_H */
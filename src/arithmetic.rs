@@ -0,0 +1,324 @@
+use crate::utils::{compare_digits, MAX_BASE};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Add two numbers written in the same base
+///
+/// Schoolbook addition with carry, least significant digit first. The
+/// operands may have different lengths, and the result is trimmed of
+/// leading zeros (zero is `[0]`).
+pub fn add_digits(a: &[u64], b: &[u64], base: u64) -> Vec<u64> {
+    validate(a, base);
+    validate(b, base);
+
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+
+    for (i, &digit) in long.iter().enumerate() {
+        let sum = digit + short.get(i).copied().unwrap_or(0) + carry;
+        result.push(sum % base);
+        carry = sum / base;
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+
+    trim(result)
+}
+
+/// Subtract `b` from `a`, both written in the same base
+///
+/// Returns `None` when `b > a`, since the difference would be negative;
+/// `compare_digits` decides that before any work is done. Otherwise the
+/// digits are subtracted with borrow, least significant first, and the
+/// result is trimmed like `add_digits` (zero is `[0]`).
+pub fn sub_digits(a: &[u64], b: &[u64], base: u64) -> Option<Vec<u64>> {
+    validate(a, base);
+    validate(b, base);
+
+    if compare_digits(a, b) == Ordering::Less {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0u64;
+
+    for (i, &digit) in a.iter().enumerate() {
+        let subtrahend = b.get(i).copied().unwrap_or(0) + borrow;
+        if digit >= subtrahend {
+            result.push(digit - subtrahend);
+            borrow = 0;
+        } else {
+            result.push(digit + base - subtrahend);
+            borrow = 1;
+        }
+    }
+
+    Some(trim(result))
+}
+
+/// Multiply a number by a single `u64`
+///
+/// The counterpart of `div_small`: one pass from the least significant
+/// digit with the running `digit * factor + carry` in u128, so any
+/// `factor` works. The product is trimmed like `add_digits` (zero is
+/// `[0]`).
+pub fn mul_small(digits: &[u64], factor: u64, base: u64) -> Vec<u64> {
+    validate(digits, base);
+
+    let mut product = digits.to_vec();
+    mul_add_in_place(&mut product, factor, 0, base);
+    trim(product)
+}
+
+/// Divide a number by a single `u64`, returning quotient digits and remainder
+///
+/// One pass of schoolbook long division from the most significant digit,
+/// the step `convert_base` repeats. The quotient is in the same base as
+/// the input and trimmed like `add_digits` (zero is `[0]`). The running
+/// `carry * base + digit` is computed in u128, so any nonzero `divisor`
+/// works, even one larger than the base. Panics if `divisor` is zero.
+pub fn div_small(digits: &[u64], divisor: u64, base: u64) -> (Vec<u64>, u64) {
+    validate(digits, base);
+    if divisor == 0 {
+        panic!("Divisor must be nonzero");
+    }
+
+    let mut quotient = vec![0u64; digits.len()];
+    let mut carry = 0u128;
+    for (i, &digit) in digits.iter().enumerate().rev() {
+        let value = carry * base as u128 + digit as u128;
+        quotient[i] = (value / divisor as u128) as u64;
+        carry = value % divisor as u128;
+    }
+
+    (trim(quotient), carry as u64)
+}
+
+/// The remainder of a number in `from_base` divided by `m`
+///
+/// Horner's method over the digits, most significant first, keeping only
+/// the running remainder. O(n) and allocation-free, which is much cheaper
+/// than converting to base `m` to read off the last digit. The step is
+/// done in u128, so any `m` works. Panics if `m` is zero.
+pub fn modulo_small(digits: &[u64], from_base: u64, m: u64) -> u64 {
+    validate(digits, from_base);
+    if m == 0 {
+        panic!("Modulus must be nonzero");
+    }
+
+    let mut acc = 0u128;
+    for &digit in digits.iter().rev() {
+        acc = (acc * from_base as u128 + digit as u128) % m as u128;
+    }
+
+    acc as u64
+}
+
+/// A number built up one digit at a time, kept in `base`
+///
+/// Each `push_digit` appends a least significant digit in the input base,
+/// `value = value * input_base + digit`, done as one multiply-add pass
+/// over the stored digits. A streaming parser can feed digits as it reads
+/// them and end up with the value already in the target base. Every push
+/// touches all stored digits, so `n` digits cost O(n^2) in total, the
+/// same as the repeated-division loop; `convert_base` on the collected
+/// input is much faster for long numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accumulator {
+    input_base: u64,
+    base: u64,
+    digits: Vec<u64>,
+}
+
+impl Accumulator {
+    /// An accumulator that takes digits in `base` and stores them in `base`
+    pub fn new(base: u64) -> Self {
+        Self::with_input_base(base, base)
+    }
+
+    /// An accumulator that takes digits in `input_base` and stores `base`
+    pub fn with_input_base(input_base: u64, base: u64) -> Self {
+        for b in [input_base, base] {
+            if !(2..=MAX_BASE).contains(&b) {
+                panic!("Bases must be between 2 and {}", MAX_BASE);
+            }
+        }
+        Accumulator { input_base, base, digits: vec![0] }
+    }
+
+    /// Append `digit` as the new least significant input digit
+    ///
+    /// Panics if `digit` is not below the input base.
+    pub fn push_digit(&mut self, digit: u64) {
+        if digit >= self.input_base {
+            panic!("Invalid digit {} for base {}", digit, self.input_base);
+        }
+        mul_add_in_place(&mut self.digits, self.input_base, digit, self.base);
+    }
+
+    /// The value so far in `base`, least significant first (zero is `[0]`)
+    pub fn digits(&self) -> &[u64] {
+        &self.digits
+    }
+
+    /// Take the stored digits, as returned by `digits`
+    pub fn into_digits(self) -> Vec<u64> {
+        self.digits
+    }
+}
+
+/// `digits = digits * factor + addend`, growing `digits` as needed
+///
+/// A zero number stays a single `[0]` limb instead of gaining more zeros.
+fn mul_add_in_place(digits: &mut Vec<u64>, factor: u64, addend: u64, base: u64) {
+    let mut carry = addend as u128;
+    for digit in digits.iter_mut() {
+        let value = *digit as u128 * factor as u128 + carry;
+        *digit = (value % base as u128) as u64;
+        carry = value / base as u128;
+    }
+    while carry > 0 {
+        digits.push((carry % base as u128) as u64);
+        carry /= base as u128;
+    }
+}
+
+fn validate(digits: &[u64], base: u64) {
+    if !(2..=MAX_BASE).contains(&base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    for &digit in digits {
+        if digit >= base {
+            panic!("Invalid digit {} for base {}", digit, base);
+        }
+    }
+}
+
+fn trim(mut digits: Vec<u64>) -> Vec<u64> {
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_with_carry() {
+        assert_eq!(add_digits(&[9], &[1], 10), vec![0, 1]);
+        assert_eq!(add_digits(&[9, 9], &[1], 10), vec![0, 0, 1]);
+        assert_eq!(add_digits(&[1], &[9, 9], 10), vec![0, 0, 1]);
+        assert_eq!(add_digits(&[65535], &[65535], 65536), vec![65534, 1]);
+    }
+
+    #[test]
+    fn test_add_zero() {
+        assert_eq!(add_digits(&[], &[], 10), vec![0]);
+        assert_eq!(add_digits(&[0, 0], &[0], 2), vec![0]);
+        assert_eq!(add_digits(&[3, 2, 0], &[], 10), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_sub_with_borrow() {
+        assert_eq!(sub_digits(&[0, 0, 1], &[1], 10), Some(vec![9, 9]));
+        assert_eq!(sub_digits(&[5, 5, 2], &[5, 5, 2], 10), Some(vec![0]));
+        assert_eq!(sub_digits(&[3, 2], &[], 10), Some(vec![3, 2]));
+        assert_eq!(sub_digits(&[0, 1], &[1, 0, 0], 2), Some(vec![1]));
+        assert_eq!(sub_digits(&[0, 0, 1], &[u32::MAX as u64], 1 << 32), Some(vec![1, u32::MAX as u64]));
+    }
+
+    #[test]
+    fn test_sub_underflow() {
+        assert_eq!(sub_digits(&[1], &[2], 10), None);
+        assert_eq!(sub_digits(&[9, 9], &[0, 0, 1], 10), None);
+        assert_eq!(sub_digits(&[], &[1], 10), None);
+        assert!(std::panic::catch_unwind(|| sub_digits(&[10], &[1], 10)).is_err());
+    }
+
+    #[test]
+    fn test_div_small() {
+        // 12345 = 7 * 1763 + 4
+        assert_eq!(div_small(&[5, 4, 3, 2, 1], 7, 10), (vec![3, 6, 7, 1], 4));
+        assert_eq!(div_small(&[5, 4, 3, 2, 1], 1, 10), (vec![5, 4, 3, 2, 1], 0));
+        assert_eq!(div_small(&[5, 4, 3, 2, 1], 100_000, 10), (vec![0], 12345));
+        assert_eq!(div_small(&[], 7, 10), (vec![0], 0));
+
+        // 2^96 - 1 by 2^64 - 1: quotient 2^32, remainder 2^32 - 1
+        let max = u32::MAX as u64;
+        assert_eq!(div_small(&[max; 3], u64::MAX, 1 << 32), (vec![0, 1], max));
+        assert!(std::panic::catch_unwind(|| div_small(&[1], 0, 10)).is_err());
+    }
+
+    #[test]
+    fn test_mul_small() {
+        assert_eq!(mul_small(&[5, 2, 1], 8, 10), vec![0, 0, 0, 1]);
+        assert_eq!(mul_small(&[5, 2, 1], 0, 10), vec![0]);
+        assert_eq!(mul_small(&[], 7, 10), vec![0]);
+        // 2^32 - 1 times 2^64 - 1 in base 2^32
+        let max = u32::MAX as u64;
+        assert_eq!(mul_small(&[max], u64::MAX, 1 << 32), vec![1, max, max - 1]);
+        assert_eq!(div_small(&mul_small(&[3, 6, 7, 1], 7, 10), 7, 10), (vec![3, 6, 7, 1], 0));
+    }
+
+    #[test]
+    fn test_accumulator() {
+        let mut acc = Accumulator::new(10);
+        assert_eq!(acc.digits(), &[0]);
+        for d in [1, 2, 3, 4, 5] {
+            acc.push_digit(d);
+        }
+        assert_eq!(acc.digits(), &[5, 4, 3, 2, 1]);
+
+        // Parse decimal straight into hex: 12345 = 0x3039
+        let mut acc = Accumulator::with_input_base(10, 16);
+        for d in [0, 0, 1, 2, 3, 4, 5] {
+            acc.push_digit(d);
+        }
+        assert_eq!(acc.into_digits(), vec![9, 3, 0, 3]);
+
+        let digits: Vec<u64> = (0..200).map(|i| (i * 7919 + 13) % 1000).collect();
+        let mut acc = Accumulator::with_input_base(1000, 7);
+        for &d in digits.iter().rev() {
+            acc.push_digit(d);
+        }
+        assert_eq!(acc.digits(), crate::optimized::convert_base(&digits, 1000, 7));
+
+        assert!(std::panic::catch_unwind(|| Accumulator::new(10).push_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_modulo_small() {
+        assert_eq!(modulo_small(&[5, 2, 1], 10, 7), 125 % 7);
+        assert_eq!(modulo_small(&[], 10, 7), 0);
+        assert_eq!(modulo_small(&[1, 1], 2, 1), 0);
+        // 2^96 - 1 needs the u128 step; 2^64 is 1 mod 2^64 - 1
+        assert_eq!(modulo_small(&[u32::MAX as u64; 3], 1 << 32, u64::MAX), u32::MAX as u64);
+        assert!(std::panic::catch_unwind(|| modulo_small(&[1], 10, 0)).is_err());
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_modulo_small_matches_biguint() {
+        use crate::bigint::to_biguint;
+        use num_bigint::BigUint;
+
+        let digits: Vec<u64> = (0..200).map(|i| (i * 7919 + 13) % 1000).collect();
+        for m in [1, 2, 97, 65521, 1 << 40, u64::MAX - 58] {
+            let expected = to_biguint(&digits, 1000) % BigUint::from(m);
+            assert_eq!(BigUint::from(modulo_small(&digits, 1000, m)), expected);
+        }
+    }
+
+    #[test]
+    fn test_invalid_digit() {
+        assert!(std::panic::catch_unwind(|| add_digits(&[10], &[1], 10)).is_err());
+    }
+}
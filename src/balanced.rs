@@ -0,0 +1,73 @@
+use crate::optimized::convert_base;
+use alloc::vec::Vec;
+
+/// Convert to a balanced base, with signed digits least significant first
+///
+/// Balanced base `b` uses the digits `-(b-1)/2..=(b-1)/2` instead of
+/// `0..b`, so balanced ternary writes 5 as `[-1, -1, 1]` (9 - 3 - 1). The
+/// value is converted with `convert_base` and then carried once from the
+/// least significant end: a digit above `(b-1)/2` becomes `digit - b` and
+/// carries one into the next place. That range only covers every residue
+/// exactly once when `to_base` is odd, so even bases panic, as do the
+/// usual invalid bases and digits. Zero is `[0]`.
+pub fn convert_to_balanced(digits: &[u64], from_base: u64, to_base: u64) -> Vec<i64> {
+    if to_base.is_multiple_of(2) {
+        panic!("Balanced bases must be odd, got {}", to_base);
+    }
+
+    let half = (to_base - 1) / 2;
+    let mut result = Vec::with_capacity(digits.len() + 1);
+    let mut carry = 0;
+    for digit in convert_base(digits, from_base, to_base) {
+        let digit = digit + carry;
+        if digit > half {
+            result.push(digit as i64 - to_base as i64);
+            carry = 1;
+        } else {
+            result.push(digit as i64);
+            carry = 0;
+        }
+    }
+    if carry > 0 {
+        result.push(1);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(digits: &[i64], base: i64) -> i128 {
+        digits.iter().rev().fold(0, |acc, &d| acc * base as i128 + d as i128)
+    }
+
+    #[test]
+    fn test_balanced_ternary() {
+        assert_eq!(convert_to_balanced(&[5], 10, 3), vec![-1, -1, 1]);
+        assert_eq!(convert_to_balanced(&[1], 10, 3), vec![1]);
+        assert_eq!(convert_to_balanced(&[2], 10, 3), vec![-1, 1]);
+        assert_eq!(convert_to_balanced(&[0, 0], 10, 3), vec![0]);
+        assert_eq!(convert_to_balanced(&[], 10, 3), vec![0]);
+    }
+
+    #[test]
+    fn test_balanced_digits_in_range() {
+        for to_base in [3u64, 5, 9, 4_294_967_295] {
+            let half = (to_base as i64 - 1) / 2;
+            for n in [1u64, 4, 13, 121, 1 << 40, u64::MAX] {
+                let input = convert_base(&[n & u32::MAX as u64, n >> 32], 1 << 32, 10);
+                let balanced = convert_to_balanced(&input, 10, to_base);
+                assert!(balanced.iter().all(|d| d.abs() <= half), "{} in base {}: {:?}", n, to_base, balanced);
+                assert_eq!(value(&balanced, to_base as i64), n as i128);
+                assert_ne!(balanced.last(), Some(&0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_even_base_panics() {
+        assert!(std::panic::catch_unwind(|| convert_to_balanced(&[5], 10, 4)).is_err());
+    }
+}
@@ -1,27 +1,32 @@
+use crate::utils::{is_zero, MAX_BASE};
+use alloc::vec;
+use alloc::vec::Vec;
+
 /// Perform base conversion using simple division algorithm
 pub fn convert_base(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
-    if from_base < 2 || from_base > 65536 || to_base < 2 || to_base > 65536 {
-        panic!("Bases must be between 2 and 65536");
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
     }
 
-    if from_base == to_base {
-        return digits.to_vec();
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
     }
 
-    if digits.is_empty() || (digits.len() == 1 && digits[0] == 0) {
+    // Every form of zero ([], [0], [0, 0], ...) comes back as exactly [0]
+    if is_zero(digits) {
         return vec![0];
     }
 
-    for &digit in digits {
-        if digit >= from_base {
-            panic!("Invalid digit {} for base {}", digit, from_base);
-        }
+    if from_base == to_base {
+        return digits.to_vec();
     }
 
     let mut current = digits.to_vec();
     let mut result = Vec::new();
 
-    while !current.is_empty() && !(current.len() == 1 && current[0] == 0) {
+    while !is_zero(&current) {
         let mut carry = 0u64;
         let mut next_current = Vec::new();
 
@@ -47,6 +52,75 @@ pub fn convert_base(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
     result
 }
 
+/// Lazily produce output digits, least significant first
+///
+/// Each call to `next` performs one division pass of the loop in
+/// `convert_base` and yields its remainder. Consuming the whole iterator
+/// is O(n²) just like `convert_base`, but stopping early (for example
+/// after the lowest few digits for a modular check) skips the rest of
+/// the work. Panics on invalid bases or digits before any output.
+pub fn convert_base_iter(digits: &[u64], from_base: u64, to_base: u64) -> ConvertBaseIter {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
+
+    let mut current = digits.to_vec();
+    while current.last() == Some(&0) {
+        current.pop();
+    }
+
+    ConvertBaseIter {
+        current,
+        from_base,
+        to_base,
+        started: false,
+    }
+}
+
+/// Iterator returned by `convert_base_iter`
+#[derive(Debug, Clone)]
+pub struct ConvertBaseIter {
+    current: Vec<u64>,
+    from_base: u64,
+    to_base: u64,
+    started: bool,
+}
+
+impl Iterator for ConvertBaseIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.current.is_empty() {
+            // Zero still has one digit
+            if self.started {
+                return None;
+            }
+            self.started = true;
+            return Some(0);
+        }
+        self.started = true;
+
+        let mut carry = 0u64;
+        for digit in self.current.iter_mut().rev() {
+            let value = carry * self.from_base + *digit;
+            *digit = value / self.to_base;
+            carry = value % self.to_base;
+        }
+
+        while self.current.last() == Some(&0) {
+            self.current.pop();
+        }
+
+        Some(carry)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,10 +173,32 @@ mod tests {
         assert!(std::panic::catch_unwind(|| convert_base(&input, 10, 2)).is_err());
     }
 
+    #[test]
+    fn test_same_base_invalid_digit() {
+        assert!(std::panic::catch_unwind(|| convert_base(&[999], 10, 10)).is_err());
+    }
+
     #[test]
     fn test_empty_input() {
         let input = vec![];
         let output = convert_base(&input, 10, 2);
         assert_eq!(output, vec![0]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_iter_matches_convert_base() {
+        let input = vec![9, 9, 9, 9, 9];
+        let collected: Vec<u64> = convert_base_iter(&input, 10, 16).collect();
+        assert_eq!(collected, convert_base(&input, 10, 16));
+
+        let collected: Vec<u64> = convert_base_iter(&[0, 0], 10, 2).collect();
+        assert_eq!(collected, vec![0]);
+    }
+
+    #[test]
+    fn test_iter_early_termination() {
+        let input = vec![7; 200];
+        let lowest: Vec<u64> = convert_base_iter(&input, 10, 7).take(3).collect();
+        assert_eq!(lowest, convert_base(&input, 10, 7)[..3]);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::optimized::convert_base;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+
+/// Convert a digit array into a `BigUint`
+///
+/// The digits are first regrouped into base 65536 with `convert_base`,
+/// then packed two at a time into the `u32` words `BigUint` uses.
+pub fn to_biguint(digits: &[u64], base: u64) -> BigUint {
+    let halves = convert_base(digits, base, 65536);
+    let words: Vec<u32> = halves
+        .chunks(2)
+        .map(|pair| pair[0] as u32 | (pair.get(1).copied().unwrap_or(0) as u32) << 16)
+        .collect();
+    BigUint::new(words)
+}
+
+/// Convert a `BigUint` into a digit array in the given base
+///
+/// The inverse of `to_biguint`. Zero becomes `[0]`.
+pub fn from_biguint(n: &BigUint, base: u64) -> Vec<u64> {
+    let mut halves = Vec::new();
+    for word in n.iter_u32_digits() {
+        halves.push((word & 0xFFFF) as u64);
+        halves.push((word >> 16) as u64);
+    }
+    while halves.len() > 1 && halves.last() == Some(&0) {
+        halves.pop();
+    }
+    if halves.is_empty() {
+        halves.push(0);
+    }
+    convert_base(&halves, 65536, base)
+}
+
+/// Convert between bases that are themselves arbitrary-precision integers
+///
+/// Digits are least significant first, as in `convert_base`, and each one
+/// must be below `from_base`. This is plain repeated long division on
+/// `BigUint` values, with none of the u64 fast paths, so it is much slower
+/// than `convert_base` and only worth it for bases beyond `MAX_BASE`, such
+/// as 2^64 limbs or a large prime. Zero becomes `[0]` and equal bases
+/// return the input as is. Panics if a base is below 2 or a digit is out
+/// of range.
+pub fn convert_base_big(digits: &[BigUint], from_base: &BigUint, to_base: &BigUint) -> Vec<BigUint> {
+    let two = BigUint::from(2u32);
+    if *from_base < two || *to_base < two {
+        panic!("Bases must be at least 2");
+    }
+    for digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
+
+    if from_base == to_base {
+        return digits.to_vec();
+    }
+
+    let zero = BigUint::from(0u32);
+    let significant = digits.iter().rposition(|d| *d != zero).map_or(0, |i| i + 1);
+    if significant == 0 {
+        return vec![zero];
+    }
+
+    // Most significant first, so each pass can drop the leading zeros it produces
+    let mut current: Vec<BigUint> = digits[..significant].iter().rev().cloned().collect();
+    let mut result = Vec::new();
+
+    while !current.is_empty() {
+        let mut carry = zero.clone();
+        let mut next = Vec::with_capacity(current.len());
+        for digit in &current {
+            let value = carry * from_base + digit;
+            let quotient = &value / to_base;
+            carry = value % to_base;
+            if !next.is_empty() || quotient != zero {
+                next.push(quotient);
+            }
+        }
+        result.push(carry);
+        current = next;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_biguint() {
+        assert_eq!(to_biguint(&[5, 5, 2], 10), BigUint::from(255u32));
+        assert_eq!(to_biguint(&[0], 7), BigUint::from(0u32));
+
+        let digits: Vec<u64> = "123456789012345678901234567890"
+            .bytes()
+            .rev()
+            .map(|b| (b - b'0') as u64)
+            .collect();
+        let expected = BigUint::parse_bytes(b"123456789012345678901234567890", 10).unwrap();
+        assert_eq!(to_biguint(&digits, 10), expected);
+    }
+
+    #[test]
+    fn test_from_biguint() {
+        assert_eq!(from_biguint(&BigUint::from(255u32), 16), vec![15, 15]);
+        assert_eq!(from_biguint(&BigUint::from(0u32), 10), vec![0]);
+        assert_eq!(from_biguint(&BigUint::from(65536u32), 65536), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_convert_base_big_from_u64_limbs() {
+        let limbs = [u64::MAX, 0x0123_4567_89ab_cdef, 42, 0, 7];
+        let from_base = BigUint::from(1u32) << 64;
+        let digits: Vec<BigUint> = limbs.iter().map(|&limb| BigUint::from(limb)).collect();
+
+        let value = limbs.iter().rev().fold(BigUint::from(0u32), |acc, &limb| (acc << 64) + limb);
+        let expected: Vec<BigUint> = value.to_str_radix(10).bytes().rev().map(|b| BigUint::from(b - b'0')).collect();
+
+        assert_eq!(convert_base_big(&digits, &from_base, &BigUint::from(10u32)), expected);
+    }
+
+    #[test]
+    fn test_convert_base_big_large_prime_round_trip() {
+        let prime = BigUint::parse_bytes(b"170141183460469231731687303715884105727", 10).unwrap(); // 2^127 - 1
+        let decimal: Vec<BigUint> = (0..120u32).map(|i| BigUint::from((i * 7 + 3) % 10)).collect();
+        let ten = BigUint::from(10u32);
+
+        let converted = convert_base_big(&decimal, &ten, &prime);
+        assert_eq!(converted.len(), 4);
+        assert_eq!(convert_base_big(&converted, &prime, &ten), decimal);
+    }
+
+    #[test]
+    fn test_convert_base_big_edges() {
+        let ten = BigUint::from(10u32);
+        let sixteen = BigUint::from(16u32);
+        let small = |digits: &[u32]| digits.iter().map(|&d| BigUint::from(d)).collect::<Vec<_>>();
+
+        assert_eq!(convert_base_big(&small(&[5, 5, 2]), &ten, &sixteen), small(&[15, 15]));
+        assert_eq!(convert_base_big(&small(&[0, 0]), &ten, &sixteen), small(&[0]));
+        assert_eq!(convert_base_big(&[], &ten, &sixteen), small(&[0]));
+        assert_eq!(convert_base_big(&small(&[5, 0]), &ten, &ten), small(&[5, 0]));
+        assert!(std::panic::catch_unwind(|| convert_base_big(&small(&[10]), &ten, &sixteen)).is_err());
+        assert!(std::panic::catch_unwind(|| convert_base_big(&small(&[1]), &BigUint::from(1u32), &ten)).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let n = BigUint::parse_bytes(b"deadbeefcafebabe0123456789abcdef55", 16).unwrap();
+        for base in [2, 3, 10, 36, 1000, 65535] {
+            assert_eq!(to_biguint(&from_biguint(&n, base), base), n);
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use crate::utils::{is_zero, MAX_BASE};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Inputs up to this many digits are converted directly with Horner's method
+const LEAF_SIZE: usize = 32;
+
+/// Operands shorter than this are multiplied with the schoolbook method
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Sub-numbers at least this long convert their halves on separate threads
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// Perform base conversion by recursively splitting the number
+///
+/// The input is split as `hi * from_base^k + lo`, both halves are converted
+/// recursively and recombined by multiplying `hi` by the precomputed
+/// representation of `from_base^k` in `to_base`. With Karatsuba
+/// multiplication this is sub-quadratic, and it already beats repeated
+/// division at around a hundred digits; `convert_base` switches to it at
+/// 64 digits.
+pub fn convert_base(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
+
+    if is_zero(digits) {
+        return vec![0];
+    }
+
+    if from_base == to_base {
+        return digits.to_vec();
+    }
+
+    let mut result = Vec::new();
+    convert_into(digits, from_base, to_base, &mut result);
+    result
+}
+
+/// Divide-and-conquer conversion with the two halves of large splits
+/// converted in parallel on the rayon thread pool
+///
+/// The result is identical to `convert_base`. Parallelism only starts to
+/// help at around 4096 input digits, where a serial conversion takes about
+/// half a millisecond; smaller splits run serially because task overhead
+/// would dominate. Each level's recombining multiplication is still serial,
+/// so the speedup stays well below the number of cores.
+#[cfg(feature = "rayon")]
+pub fn convert_base_parallel(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
+
+    if is_zero(digits) {
+        return vec![0];
+    }
+
+    if from_base == to_base {
+        return digits.to_vec();
+    }
+
+    let digits = trim(digits);
+    let powers = split_powers(digits.len(), from_base, to_base);
+    convert_recursive_parallel(digits, from_base, to_base, &powers)
+}
+
+/// Convert pre-validated digits, writing the trimmed result into `result`
+pub(crate) fn convert_into(digits: &[u64], from_base: u64, to_base: u64, result: &mut Vec<u64>) {
+    let digits = trim(digits);
+    let powers = split_powers(digits.len(), from_base, to_base);
+
+    let converted = convert_recursive(digits, from_base, to_base, &powers);
+    result.clear();
+    result.extend_from_slice(&converted);
+}
+
+fn trim(digits: &[u64]) -> &[u64] {
+    let mut len = digits.len();
+    while len > 1 && digits[len - 1] == 0 {
+        len -= 1;
+    }
+    &digits[..len]
+}
+
+/// `powers[i] = from_base^(LEAF_SIZE << i)`, written in `to_base`, for every
+/// split point an input of `len` digits needs
+fn split_powers(len: usize, from_base: u64, to_base: u64) -> Vec<Vec<u64>> {
+    let mut leaf_power = vec![0; LEAF_SIZE + 1];
+    leaf_power[LEAF_SIZE] = 1;
+    let mut powers = vec![convert_leaf(&leaf_power, from_base, to_base)];
+    while (LEAF_SIZE << powers.len()) < len {
+        let last = &powers[powers.len() - 1];
+        let squared = mul_add(last, last, &[], to_base);
+        powers.push(squared);
+    }
+    powers
+}
+
+fn convert_recursive(digits: &[u64], from_base: u64, to_base: u64, powers: &[Vec<u64>]) -> Vec<u64> {
+    if digits.len() <= LEAF_SIZE {
+        return convert_leaf(digits, from_base, to_base);
+    }
+
+    // Split at the largest precomputed power below the input length
+    let level = (usize::BITS - 1 - ((digits.len() - 1) / LEAF_SIZE).leading_zeros()) as usize;
+    let (lo, hi) = digits.split_at(LEAF_SIZE << level);
+
+    let lo = convert_recursive(lo, from_base, to_base, powers);
+    let hi = convert_recursive(hi, from_base, to_base, powers);
+
+    mul_add(&hi, &powers[level], &lo, to_base)
+}
+
+#[cfg(feature = "rayon")]
+fn convert_recursive_parallel(digits: &[u64], from_base: u64, to_base: u64, powers: &[Vec<u64>]) -> Vec<u64> {
+    if digits.len() < PARALLEL_THRESHOLD {
+        return convert_recursive(digits, from_base, to_base, powers);
+    }
+
+    let level = (usize::BITS - 1 - ((digits.len() - 1) / LEAF_SIZE).leading_zeros()) as usize;
+    let (lo, hi) = digits.split_at(LEAF_SIZE << level);
+
+    let (lo, hi) = rayon::join(
+        || convert_recursive_parallel(lo, from_base, to_base, powers),
+        || convert_recursive_parallel(hi, from_base, to_base, powers),
+    );
+
+    mul_add(&hi, &powers[level], &lo, to_base)
+}
+
+/// Convert a short digit slice with Horner's method in the target base
+fn convert_leaf(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    let mut result = vec![0];
+
+    for &digit in digits.iter().rev() {
+        let mut carry = digit;
+        for limb in result.iter_mut() {
+            let value = *limb * from_base + carry;
+            *limb = value % to_base;
+            carry = value / to_base;
+        }
+        while carry > 0 {
+            result.push(carry % to_base);
+            carry /= to_base;
+        }
+    }
+
+    while result.len() > 1 && result.last() == Some(&0) {
+        result.pop();
+    }
+
+    result
+}
+
+/// Largest base whose polynomial products fit in u64 coefficients
+const NARROW_COEFF_MAX_BASE: u64 = 65536;
+
+/// `a * b + c` in `base`, where `c` has no more digits than the product
+///
+/// Coefficients are u64 while `(base - 1)^2` times the operand length
+/// fits, which holds for bases up to 65536; larger bases multiply in u128.
+fn mul_add(a: &[u64], b: &[u64], c: &[u64], base: u64) -> Vec<u64> {
+    if base <= NARROW_COEFF_MAX_BASE {
+        let mut coeffs = poly_mul(a, b);
+        for (x, &d) in coeffs.iter_mut().zip(c) {
+            *x = x.wrapping_add(d);
+        }
+        normalize(coeffs, base)
+    } else {
+        let widen = |digits: &[u64]| digits.iter().map(|&d| d as u128).collect::<Vec<u128>>();
+        let mut coeffs = poly_mul(&widen(a), &widen(b));
+        for (x, &d) in coeffs.iter_mut().zip(c) {
+            *x = x.wrapping_add(d as u128);
+        }
+        normalize(coeffs, base)
+    }
+}
+
+/// Polynomial coefficient: u64, or u128 for bases above 65536
+trait Coeff: Copy {
+    const ZERO: Self;
+
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_coeff {
+    ($($t:ty),*) => {
+        $(
+            impl Coeff for $t {
+                const ZERO: Self = 0;
+
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$t>::wrapping_add(self, other)
+                }
+                fn wrapping_sub(self, other: Self) -> Self {
+                    <$t>::wrapping_sub(self, other)
+                }
+                fn wrapping_mul(self, other: Self) -> Self {
+                    <$t>::wrapping_mul(self, other)
+                }
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_coeff!(u64, u128);
+
+/// Propagate carries so every coefficient becomes a digit below `base`
+///
+/// Each coefficient plus the incoming carry must fit in u128: true for u64
+/// coefficients, and for u128 ones the product bound keeps them far below.
+fn normalize<C: Coeff>(coeffs: Vec<C>, base: u64) -> Vec<u64> {
+    let mut digits = Vec::with_capacity(coeffs.len() + 2);
+    let mut carry = 0u128;
+
+    for c in coeffs {
+        let value = c.to_u128() + carry;
+        digits.push((value % base as u128) as u64);
+        carry = value / base as u128;
+    }
+    while carry > 0 {
+        digits.push((carry % base as u128) as u64);
+        carry /= base as u128;
+    }
+
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push(0);
+    }
+
+    digits
+}
+
+/// Multiply two digit slices as polynomials, without carrying
+///
+/// Arithmetic wraps modulo the coefficient width. Karatsuba's intermediate
+/// sums can exceed it, but the final coefficients are bounded by
+/// `min(a.len(), b.len()) * (base - 1)^2`. That fits in u64 for any base up
+/// to 65536 and in u128 for any base up to 2^32, so the wrapped result is
+/// exact.
+fn poly_mul<C: Coeff>(a: &[C], b: &[C]) -> Vec<C> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    if a.len() < KARATSUBA_THRESHOLD || b.len() < KARATSUBA_THRESHOLD {
+        let mut result = vec![C::ZERO; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                result[i + j] = result[i + j].wrapping_add(x.wrapping_mul(y));
+            }
+        }
+        return result;
+    }
+
+    let m = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+
+    let z0 = poly_mul(a0, b0);
+    let z2 = poly_mul(a1, b1);
+    let z1 = poly_mul(&poly_add(a0, a1), &poly_add(b0, b1));
+
+    let mut result = vec![C::ZERO; a.len() + b.len() - 1];
+    for (i, &c) in z0.iter().enumerate() {
+        result[i] = result[i].wrapping_add(c);
+    }
+    for (i, &c) in z2.iter().enumerate() {
+        result[i + 2 * m] = result[i + 2 * m].wrapping_add(c);
+    }
+    for (i, &c) in z1.iter().enumerate() {
+        let low = z0.get(i).copied().unwrap_or(C::ZERO);
+        let high = z2.get(i).copied().unwrap_or(C::ZERO);
+        let middle = c.wrapping_sub(low).wrapping_sub(high);
+        result[i + m] = result[i + m].wrapping_add(middle);
+    }
+
+    result
+}
+
+fn poly_add<C: Coeff>(a: &[C], b: &[C]) -> Vec<C> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = long.to_vec();
+    for (r, &x) in result.iter_mut().zip(short) {
+        *r = r.wrapping_add(x);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_inputs() {
+        assert_eq!(convert_base(&[5, 5, 2], 10, 16), vec![15, 15]);
+        assert_eq!(convert_base(&[0], 10, 2), vec![0]);
+        assert_eq!(convert_base(&[], 10, 2), vec![0]);
+        assert_eq!(convert_base(&[0, 0, 0], 10, 2), vec![0]);
+    }
+
+    #[test]
+    fn test_matches_baseline() {
+        for len in [33, 100, 257, 1000, 3000] {
+            let input: Vec<u64> = (0..len).map(|i| (i * 7 + 3) % 10).collect();
+            for to_base in [2, 7, 16, 1000, 65536] {
+                assert_eq!(
+                    convert_base(&input, 10, to_base),
+                    crate::baseline::convert_base(&input, 10, to_base),
+                    "len {} to base {}",
+                    len,
+                    to_base
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_bases() {
+        let input: Vec<u64> = (0..500).map(|i| (i * 40503 + 17) % 65536).collect();
+        assert_eq!(
+            convert_base(&input, 65536, 65521),
+            crate::baseline::convert_base(&input, 65536, 65521)
+        );
+    }
+
+    #[test]
+    fn test_power_of_from_base() {
+        // 10^1000 has leading and internal zero runs on every split
+        let mut input = vec![0; 1001];
+        input[1000] = 1;
+        assert_eq!(convert_base(&input, 10, 3), crate::baseline::convert_base(&input, 10, 3));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_baseline() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0x5eed);
+        for _ in 0..4 {
+            let len = rng.gen_range(PARALLEL_THRESHOLD..3 * PARALLEL_THRESHOLD);
+            let from_base = rng.gen_range(2..=65536);
+            let to_base = rng.gen_range(2..=65536);
+            let input: Vec<u64> = (0..len).map(|_| rng.gen_range(0..from_base)).collect();
+            assert_eq!(
+                convert_base_parallel(&input, from_base, to_base),
+                crate::baseline::convert_base(&input, from_base, to_base),
+                "len {} from base {} to base {}",
+                len,
+                from_base,
+                to_base
+            );
+        }
+
+        assert_eq!(convert_base_parallel(&[5, 5, 2], 10, 16), vec![15, 15]);
+        assert_eq!(convert_base_parallel(&[0, 0], 10, 16), vec![0]);
+    }
+
+    #[test]
+    fn test_poly_mul_matches_schoolbook() {
+        let a: Vec<u64> = (0..150).map(|i| (i * 31 + 7) % 65536).collect();
+        let b: Vec<u64> = (0..97).map(|i| (i * 17 + 3) % 65536).collect();
+
+        let mut expected = vec![0u64; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                expected[i + j] += x * y;
+            }
+        }
+
+        assert_eq!(poly_mul(&a, &b), expected);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::error::ConvertError;
+use crate::optimized::{convert_base, convert_base_padded, convert_base_with_leading};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The Bitcoin base58 alphabet: no `0`, `O`, `I` or `l`
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The standard base64 alphabet from RFC 4648
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode bytes as base58 with the Bitcoin alphabet
+///
+/// The bytes are one big-endian number, converted to base 58 with
+/// `convert_base_with_leading`. Each leading zero byte becomes a leading
+/// `1`, so leading zeros survive a round trip. Empty input encodes to the
+/// empty string.
+pub fn to_base58(bytes: &[u8]) -> String {
+    let little_endian: Vec<u64> = bytes.iter().rev().map(|&b| b as u64).collect();
+    let (zeros, digits) = convert_base_with_leading(&little_endian, 256, 58);
+
+    let mut s = String::with_capacity(zeros + digits.len());
+    s.extend(core::iter::repeat_n('1', zeros));
+    s.extend(digits.iter().rev().map(|&d| BASE58_ALPHABET[d as usize] as char));
+    s
+}
+
+/// Decode a base58 string written with the Bitcoin alphabet
+///
+/// The inverse of `to_base58`: every leading `1` is a zero byte. A symbol
+/// outside the alphabet is reported as `InvalidCharacter`.
+pub fn from_base58(s: &str) -> Result<Vec<u8>, ConvertError> {
+    let zeros = s.chars().take_while(|&c| c == '1').count();
+    let digits = s[zeros..]
+        .chars()
+        .rev()
+        .map(|c| symbol_value(BASE58_ALPHABET, c))
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    let mut bytes = vec![0u8; zeros];
+    if !digits.is_empty() {
+        bytes.extend(convert_base(&digits, 58, 256).iter().rev().map(|&d| d as u8));
+    }
+    Ok(bytes)
+}
+
+/// Encode bytes as padded base64 (RFC 4648, standard alphabet)
+///
+/// Base64 packs bits from the front, so the input is extended with zero
+/// bytes to a multiple of three, converted from base 256 to base 64 at
+/// full width, and the symbols that only carry the added zeros are
+/// replaced by `=`.
+pub fn to_base64(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+    let pad = (3 - bytes.len() % 3) % 3;
+    let width = (bytes.len() + pad) / 3 * 4;
+
+    let little_endian: Vec<u64> = core::iter::repeat_n(0, pad).chain(bytes.iter().rev().map(|&b| b as u64)).collect();
+    let digits = convert_base_padded(&little_endian, 256, 64, width);
+
+    let mut s: String = digits[pad..].iter().rev().map(|&d| BASE64_ALPHABET[d as usize] as char).collect();
+    s.extend(core::iter::repeat_n('=', pad));
+    s
+}
+
+/// Decode base64 (RFC 4648, standard alphabet), with or without padding
+///
+/// At most two trailing `=` are accepted, and only when they complete the
+/// last group of four. A symbol outside the alphabet, a misplaced `=`, or
+/// a length that cannot come from whole bytes is reported as
+/// `InvalidCharacter` with the offending symbol.
+pub fn from_base64(s: &str) -> Result<Vec<u8>, ConvertError> {
+    let body = s.strip_suffix("==").or_else(|| s.strip_suffix('=')).unwrap_or(s);
+    let pad = (4 - body.len() % 4) % 4;
+    if body.len() % 4 == 1 || (body.len() < s.len() && body.len() + pad != s.len()) {
+        let last = s.chars().next_back().unwrap_or('=');
+        return Err(ConvertError::InvalidCharacter { character: last });
+    }
+    if body.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut digits = vec![0u64; pad];
+    for c in body.chars().rev() {
+        digits.push(symbol_value(BASE64_ALPHABET, c)?);
+    }
+    let width = digits.len() / 4 * 3;
+    let bytes = convert_base_padded(&digits, 64, 256, width);
+
+    Ok(bytes[pad..].iter().rev().map(|&d| d as u8).collect())
+}
+
+fn symbol_value(alphabet: &[u8], c: char) -> Result<u64, ConvertError> {
+    alphabet
+        .iter()
+        .position(|&symbol| symbol as char == c)
+        .map(|i| i as u64)
+        .ok_or(ConvertError::InvalidCharacter { character: c })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_base58_vectors() {
+        // From Bitcoin Core's base58_encode_decode.json
+        let vectors = [
+            ("", ""),
+            ("61", "2g"),
+            ("626262", "a3gV"),
+            ("636363", "aPEr"),
+            ("73696d706c792061206c6f6e6720737472696e67", "2cFupjhnEsSn59qHXstmK2ffpLv2"),
+            ("00eb15231dfceb60925886b67d065299925915aeb172c06647", "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L"),
+            ("516b6fcd0f", "ABnLTmg"),
+            ("bf4f89001e670274dd", "3SEo3LWLoPntC"),
+            ("572e4794", "3EFU7m"),
+            ("ecac89cad93923c02321", "EJDM8drfXA6uyA"),
+            ("10c8511e", "Rt5zm"),
+            ("00000000000000000000", "1111111111"),
+        ];
+        for (bytes, encoded) in vectors {
+            assert_eq!(to_base58(&hex(bytes)), encoded);
+            assert_eq!(from_base58(encoded).unwrap(), hex(bytes));
+        }
+    }
+
+    #[test]
+    fn test_base58_invalid() {
+        assert_eq!(from_base58("1O"), Err(ConvertError::InvalidCharacter { character: 'O' }));
+        assert_eq!(from_base58("0"), Err(ConvertError::InvalidCharacter { character: '0' }));
+    }
+
+    #[test]
+    fn test_base64_vectors() {
+        // RFC 4648, section 10
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (text, encoded) in vectors {
+            assert_eq!(to_base64(text.as_bytes()), encoded);
+            assert_eq!(from_base64(encoded).unwrap(), text.as_bytes());
+            assert_eq!(from_base64(encoded.trim_end_matches('=')).unwrap(), text.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_base64_leading_zeros_and_high_bytes() {
+        let bytes = [0, 0, 0xff, 0xfe, 0];
+        assert_eq!(to_base64(&bytes), "AAD//gA=");
+        assert_eq!(from_base64("AAD//gA=").unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_base64_invalid() {
+        assert_eq!(from_base64("Zm9v!"), Err(ConvertError::InvalidCharacter { character: '!' }));
+        assert_eq!(from_base64("Zm9vY"), Err(ConvertError::InvalidCharacter { character: 'Y' }));
+        assert_eq!(from_base64("Zm8=="), Err(ConvertError::InvalidCharacter { character: '=' }));
+        assert_eq!(from_base64("Z=m8"), Err(ConvertError::InvalidCharacter { character: '=' }));
+    }
+}
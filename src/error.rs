@@ -0,0 +1,83 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Errors that can occur during base conversion
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertError {
+    /// A base was outside the supported range
+    BaseOutOfRange { base: u64 },
+    /// A digit was not valid for its base; `index` is its position in the
+    /// least-significant-first digits
+    InvalidDigit { digit: u64, base: u64, index: usize },
+    /// An alphabet with no symbols was supplied
+    EmptyAlphabet,
+    /// An alphabet contained the same symbol more than once
+    DuplicateSymbol { symbol: char },
+    /// A string contained a symbol that is not part of the alphabet
+    InvalidCharacter { character: char },
+    /// A streamed input contained an invalid symbol; `position` is its byte
+    /// offset from the start of the stream
+    InvalidCharacterAt { character: char, position: usize },
+    /// The input contained no digits at all
+    EmptyInput,
+    /// The output would need `needed` digits, more than the allowed `max`
+    OutputTooLarge { needed: usize, max: usize },
+    /// The value needs `len` digits, more than the fixed `width`
+    OverflowsFixedWidth { len: usize, width: usize },
+    /// Reading the input failed; `message` is the I/O error's text
+    Io { message: String },
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::BaseOutOfRange { .. } => write!(f, "Bases must be between 2 and {}", crate::utils::MAX_BASE),
+            ConvertError::InvalidDigit { digit, base, .. } => {
+                write!(f, "Invalid digit {} for base {}", digit, base)
+            }
+            ConvertError::EmptyAlphabet => write!(f, "Alphabet must not be empty"),
+            ConvertError::DuplicateSymbol { symbol } => {
+                write!(f, "Duplicate symbol '{}' in alphabet", symbol)
+            }
+            ConvertError::InvalidCharacter { character } => {
+                write!(f, "Invalid character: {}", character)
+            }
+            ConvertError::InvalidCharacterAt { character, position } => {
+                write!(f, "Invalid character: {} at byte {}", character, position)
+            }
+            ConvertError::EmptyInput => write!(f, "Input contains no digits"),
+            ConvertError::OutputTooLarge { needed, max } => {
+                write!(f, "Output needs {} digits, more than the limit of {}", needed, max)
+            }
+            ConvertError::OverflowsFixedWidth { len, width } => {
+                write!(f, "Value needs {} digits, more than the fixed width of {}", len, width)
+            }
+            ConvertError::Io { message } => write!(f, "I/O error: {}", message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConvertError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_matches_panic_messages() {
+        let err = ConvertError::BaseOutOfRange { base: 1 };
+        assert_eq!(err.to_string(), "Bases must be between 2 and 4294967296");
+
+        let err = ConvertError::InvalidDigit { digit: 10, base: 10, index: 0 };
+        assert_eq!(err.to_string(), "Invalid digit 10 for base 10");
+    }
+
+    #[test]
+    fn test_boxed_error() {
+        fn fails() -> Result<(), Box<dyn std::error::Error>> {
+            Err(ConvertError::EmptyAlphabet)?
+        }
+        assert_eq!(fails().unwrap_err().to_string(), "Alphabet must not be empty");
+    }
+}
@@ -0,0 +1,128 @@
+//! C interface, enabled with the `cffi` feature
+//!
+//! The functions mirror `convert_base_into`: the caller owns every buffer
+//! and the library only writes into it. Errors come back as negative
+//! return codes instead of panics, since a panic cannot unwind into C.
+//! `include/fast_base_convert.h` declares the same functions and codes.
+
+use crate::optimized::try_convert_base;
+use crate::utils::{estimate_output_len, MAX_BASE};
+use crate::ConvertError;
+use core::slice;
+
+/// The conversion succeeded
+pub const FBC_OK: i32 = 0;
+/// A required pointer was null
+pub const FBC_ERR_NULL_POINTER: i32 = -1;
+/// A base was outside 2..=2^32
+pub const FBC_ERR_BASE_OUT_OF_RANGE: i32 = -2;
+/// An input digit was not below `from_base`
+pub const FBC_ERR_INVALID_DIGIT: i32 = -3;
+/// The output did not fit in `out_cap` digits
+pub const FBC_ERR_BUFFER_TOO_SMALL: i32 = -4;
+
+/// Convert `len` digits at `digits` from `from_base` to `to_base`
+///
+/// Digits are least significant first, as in `convert_base`, and the
+/// result is the same. On success the digits are written to `out`, their
+/// count to `*out_len`, and `FBC_OK` is returned. If the result needs more
+/// than `out_cap` digits, `*out_len` is set to the length needed, nothing
+/// is written to `out` and `FBC_ERR_BUFFER_TOO_SMALL` is returned; size the
+/// buffer with `fbc_estimate_len` to avoid the retry. On any other error
+/// `*out_len` is left untouched.
+///
+/// # Safety
+///
+/// `digits` must point to `len` readable `u64`s and `out` to `out_cap`
+/// writable ones. Either may be null when its length is zero. `out_len`
+/// must point to a writable `size_t`. The buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn fbc_convert(
+    digits: *const u64,
+    len: usize,
+    from_base: u64,
+    to_base: u64,
+    out: *mut u64,
+    out_cap: usize,
+    out_len: *mut usize,
+) -> i32 {
+    if out_len.is_null() || (digits.is_null() && len > 0) || (out.is_null() && out_cap > 0) {
+        return FBC_ERR_NULL_POINTER;
+    }
+
+    let input = if len == 0 { &[][..] } else { slice::from_raw_parts(digits, len) };
+    let result = match try_convert_base(input, from_base, to_base) {
+        Ok(result) => result,
+        Err(ConvertError::BaseOutOfRange { .. }) => return FBC_ERR_BASE_OUT_OF_RANGE,
+        Err(_) => return FBC_ERR_INVALID_DIGIT,
+    };
+
+    *out_len = result.len();
+    if result.len() > out_cap {
+        return FBC_ERR_BUFFER_TOO_SMALL;
+    }
+    slice::from_raw_parts_mut(out, result.len()).copy_from_slice(&result);
+    FBC_OK
+}
+
+/// An output capacity that is always enough for `fbc_convert`
+///
+/// The same bound as `estimate_output_len`. Returns 0 when either base is
+/// out of range, since `fbc_convert` would reject the call anyway.
+#[no_mangle]
+pub extern "C" fn fbc_estimate_len(len: usize, from_base: u64, to_base: u64) -> usize {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        return 0;
+    }
+    estimate_output_len(len, from_base, to_base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    #[test]
+    fn test_convert_into_buffer() {
+        let digits = [5u64, 5, 2];
+        let mut out = [0u64; 8];
+        let mut out_len = 0;
+        let code = unsafe { fbc_convert(digits.as_ptr(), 3, 10, 16, out.as_mut_ptr(), 8, &mut out_len) };
+        assert_eq!(code, FBC_OK);
+        assert_eq!(&out[..out_len], &[15, 15]);
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let mut out = [0u64; 1];
+        let mut out_len = 0;
+        unsafe {
+            assert_eq!(fbc_convert(ptr::null(), 1, 10, 2, out.as_mut_ptr(), 1, &mut out_len), FBC_ERR_NULL_POINTER);
+            assert_eq!(fbc_convert([1].as_ptr(), 1, 10, 2, out.as_mut_ptr(), 1, ptr::null_mut()), FBC_ERR_NULL_POINTER);
+            assert_eq!(fbc_convert([1].as_ptr(), 1, 1, 2, out.as_mut_ptr(), 1, &mut out_len), FBC_ERR_BASE_OUT_OF_RANGE);
+            assert_eq!(fbc_convert([10].as_ptr(), 1, 10, 2, out.as_mut_ptr(), 1, &mut out_len), FBC_ERR_INVALID_DIGIT);
+            assert_eq!(out_len, 0);
+
+            // 255 needs eight binary digits; the needed length is reported
+            assert_eq!(fbc_convert([5, 5, 2].as_ptr(), 3, 10, 2, out.as_mut_ptr(), 1, &mut out_len), FBC_ERR_BUFFER_TOO_SMALL);
+            assert_eq!(out_len, 8);
+            assert_eq!(out, [0]);
+        }
+    }
+
+    #[test]
+    fn test_empty_input_is_zero() {
+        let mut out = [7u64; 1];
+        let mut out_len = 0;
+        let code = unsafe { fbc_convert(ptr::null(), 0, 10, 2, out.as_mut_ptr(), 1, &mut out_len) };
+        assert_eq!(code, FBC_OK);
+        assert_eq!(&out[..out_len], &[0]);
+    }
+
+    #[test]
+    fn test_estimate_len() {
+        assert!(fbc_estimate_len(3, 10, 2) >= 10);
+        assert_eq!(fbc_estimate_len(0, 10, 2), 1);
+        assert_eq!(fbc_estimate_len(3, 10, 1), 0);
+    }
+}
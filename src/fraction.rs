@@ -0,0 +1,89 @@
+use crate::optimized::convert_base;
+use alloc::vec::Vec;
+
+/// Convert a number with a radix point between bases
+///
+/// `int_digits` are least significant first like everywhere else in the
+/// crate, while `frac_digits` are in reading order: `frac_digits[0]` is the
+/// digit right after the radix point. The returned pair uses the same
+/// layout.
+///
+/// The integer part goes through `convert_base`. The fractional part is
+/// multiplied by `to_base` repeatedly, taking the digit that carries out of
+/// the radix point each time. This stops once the fraction becomes zero or
+/// after `max_frac_digits` output digits. A fraction that does not
+/// terminate in the target base (0.1 decimal in binary, for example) is
+/// truncated, not rounded, at the cap.
+pub fn convert_fraction(
+    int_digits: &[u64],
+    frac_digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    max_frac_digits: usize,
+) -> (Vec<u64>, Vec<u64>) {
+    let int_part = convert_base(int_digits, from_base, to_base);
+
+    for &digit in frac_digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
+
+    let mut current = frac_digits.to_vec();
+    while current.last() == Some(&0) {
+        current.pop();
+    }
+
+    let mut frac_part = Vec::new();
+    while !current.is_empty() && frac_part.len() < max_frac_digits {
+        let mut carry = 0u64;
+        for digit in current.iter_mut().rev() {
+            let value = *digit * to_base + carry;
+            *digit = value % from_base;
+            carry = value / from_base;
+        }
+        frac_part.push(carry);
+
+        while current.last() == Some(&0) {
+            current.pop();
+        }
+    }
+
+    (int_part, frac_part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminating_fraction() {
+        // 0.5 decimal = 0.1 binary
+        assert_eq!(convert_fraction(&[0], &[5], 10, 2, 16), (vec![0], vec![1]));
+        // 12.375 decimal = 1100.011 binary
+        assert_eq!(
+            convert_fraction(&[2, 1], &[3, 7, 5], 10, 2, 16),
+            (vec![0, 0, 1, 1], vec![0, 1, 1])
+        );
+    }
+
+    #[test]
+    fn test_repeating_fraction() {
+        // 0.1 decimal = 0.0(0011) binary, cut at the cap
+        let (int_part, frac_part) = convert_fraction(&[0], &[1], 10, 2, 10);
+        assert_eq!(int_part, vec![0]);
+        assert_eq!(frac_part, vec![0, 0, 0, 1, 1, 0, 0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_empty_and_zero_fraction() {
+        assert_eq!(convert_fraction(&[5], &[], 10, 2, 8), (vec![1, 0, 1], vec![]));
+        assert_eq!(convert_fraction(&[5], &[0, 0], 10, 2, 8), (vec![1, 0, 1], vec![]));
+    }
+
+    #[test]
+    fn test_round_trip_to_larger_base() {
+        // 0.0101 binary = 0.3125 decimal
+        assert_eq!(convert_fraction(&[0], &[0, 1, 0, 1], 2, 10, 16), (vec![0], vec![3, 1, 2, 5]));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::optimized::convert_base;
+use alloc::vec::Vec;
+use num_bigint::BigUint;
+
+/// Differential check of `convert_base` against `BigUint` arithmetic
+///
+/// The reference value is built with plain Horner steps on a `BigUint` and
+/// written out by repeated division, sharing no code with the conversion
+/// paths. The output must match exactly, so a result that is right in
+/// value but carries a stray leading zero also fails. Invalid bases or
+/// digits panic just like `convert_base`.
+pub fn check_against_biguint(digits: &[u64], from_base: u64, to_base: u64) -> bool {
+    let converted = convert_base(digits, from_base, to_base);
+    converted == reference_digits(&reference_value(digits, from_base), to_base)
+}
+
+fn reference_value(digits: &[u64], base: u64) -> BigUint {
+    let mut value = BigUint::from(0u32);
+    for &digit in digits.iter().rev() {
+        value = value * base + digit;
+    }
+    value
+}
+
+fn reference_digits(value: &BigUint, base: u64) -> Vec<u64> {
+    let base = BigUint::from(base);
+    let mut value = value.clone();
+    let mut digits = Vec::new();
+
+    loop {
+        let remainder = &value % &base;
+        digits.push(remainder.iter_u64_digits().next().unwrap_or(0));
+        value /= &base;
+        if value.bits() == 0 {
+            break;
+        }
+    }
+
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_branches_match_biguint() {
+        let long: Vec<u64> = (0..300).map(|i| (i * 7 + 3) % 16).collect();
+        for (from_base, to_base) in [(16, 2), (16, 4), (16, 8), (2, 16), (9, 27), (10, 7), (16, 1_000_000)] {
+            for len in [0, 1, 5, 30, 70, 300] {
+                let digits: Vec<u64> = long[..len].iter().map(|&d| d % from_base.min(16)).collect();
+                assert!(check_against_biguint(&digits, from_base, to_base), "{} -> {} at {} digits", from_base, to_base, len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_digits() {
+        assert_eq!(reference_digits(&BigUint::from(0u32), 10), vec![0]);
+        assert_eq!(reference_digits(&BigUint::from(255u32), 16), vec![15, 15]);
+        assert_eq!(reference_value(&[5, 5, 2], 10), BigUint::from(255u32));
+    }
+}
@@ -1,18 +1,74 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+pub mod arithmetic;
+pub mod balanced;
 pub mod baseline;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+pub mod divide_conquer;
+pub mod encoding;
+pub mod error;
+#[cfg(feature = "cffi")]
+pub mod ffi;
+pub mod fraction;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod limb;
+pub mod number;
 pub mod optimized;
+pub mod signed;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod unary;
 pub mod utils;
 
+pub use arithmetic::{add_digits, div_small, modulo_small, mul_small, sub_digits, Accumulator};
+pub use balanced::convert_to_balanced;
 pub use baseline::convert_base as convert_base_baseline;
-pub use optimized::convert_base;
+pub use baseline::{convert_base_iter, ConvertBaseIter};
+#[cfg(feature = "bigint")]
+pub use bigint::{convert_base_big, from_biguint, to_biguint};
+#[cfg(feature = "rayon")]
+pub use divide_conquer::convert_base_parallel;
+pub use encoding::{from_base58, from_base64, to_base58, to_base64};
+pub use error::ConvertError;
+pub use fraction::convert_fraction;
+#[cfg(feature = "fuzz")]
+pub use fuzz::check_against_biguint;
+pub use limb::{convert_base_generic, DigitLimb};
+pub use number::{Number, ValidatedDigits};
+pub use optimized::{
+    convert_base, convert_base_be, convert_base_capped, convert_base_exact, convert_base_explained,
+    convert_base_fixed, convert_base_in_place, convert_base_into, convert_base_many, convert_base_no_trim,
+    convert_base_padded, convert_base_unchecked, convert_base_with_leading, convert_base_with_options,
+    convert_base_with_scratch, try_convert_base,
+};
+pub use optimized::{
+    aligned_exponents, count_digits, fits_in_u128, min_base_for_digits, prime_factorization, repack_bits,
+};
+pub use optimized::{ConversionPlan, ConvertOptions, Converter, ScratchBuffers, Strategy};
+pub use signed::{convert_signed, SignedNumber};
+#[cfg(feature = "stats")]
+pub use stats::{take_stats, ConversionStats};
+pub use unary::convert_base_unary_aware;
 pub use utils::*;
 
 /// Convenience function that automatically chooses the best algorithm
+///
+/// This is `convert_base`, which already dispatches on the bases and the
+/// input size. The `tiny_inputs` benchmark compares it with
+/// `convert_base_baseline` on 1 to 16 digits, and the baseline never wins,
+/// not even on a single digit. So no input is routed to the baseline.
 pub fn convert_base_auto(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
     convert_base(digits, from_base, to_base)
 }
 
 // WASM module
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", feature = "std"))]
 pub mod wasm;
 
 #[cfg(test)]
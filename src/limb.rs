@@ -0,0 +1,89 @@
+use crate::optimized::convert_base;
+use alloc::vec::Vec;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Unsigned integer types that can hold a single digit
+///
+/// Implemented for `u8`, `u16`, `u32` and `u64`. The trait is sealed so
+/// the set of limb types can grow without breaking downstream code.
+pub trait DigitLimb: Copy + private::Sealed {
+    /// Largest value a limb of this type can hold
+    const MAX: u64;
+
+    fn to_u64(self) -> u64;
+    fn from_u64(value: u64) -> Self;
+}
+
+macro_rules! impl_digit_limb {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl DigitLimb for $t {
+                const MAX: u64 = <$t>::MAX as u64;
+
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+
+                fn from_u64(value: u64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_digit_limb!(u8, u16, u32, u64);
+
+/// Convert digits stored in any limb width, returning the same width
+///
+/// Lets callers keep `Vec<u8>` or `Vec<u16>` data for small bases instead
+/// of widening everything to `u64`. Arithmetic is done in `u64` internally.
+/// Panics if either base has digits that do not fit in `T`, in addition to
+/// the usual `convert_base` panics.
+pub fn convert_base_generic<T: DigitLimb>(digits: &[T], from_base: u64, to_base: u64) -> Vec<T> {
+    for base in [from_base, to_base] {
+        if base.saturating_sub(1) > T::MAX {
+            panic!("Base {} does not fit in a {}-bit digit", base, core::mem::size_of::<T>() * 8);
+        }
+    }
+
+    let wide: Vec<u64> = digits.iter().map(|&d| d.to_u64()).collect();
+    convert_base(&wide, from_base, to_base)
+        .into_iter()
+        .map(T::from_u64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u8_digits() {
+        let input: Vec<u8> = vec![5, 5, 2]; // 255
+        assert_eq!(convert_base_generic(&input, 10, 16), vec![15u8, 15]);
+        assert_eq!(convert_base_generic(&input, 10, 256), vec![255u8]);
+    }
+
+    #[test]
+    fn test_matches_u64_conversion() {
+        let input: Vec<u16> = (0..50).map(|i| (i * 37 + 11) % 1000).collect();
+        let wide: Vec<u64> = input.iter().map(|&d| d as u64).collect();
+        let expected: Vec<u16> = convert_base(&wide, 1000, 65536)
+            .into_iter()
+            .map(|d| d as u16)
+            .collect();
+        assert_eq!(convert_base_generic(&input, 1000, 65536), expected);
+    }
+
+    #[test]
+    fn test_base_too_wide_for_limb() {
+        assert!(std::panic::catch_unwind(|| convert_base_generic(&[1u8], 10, 257)).is_err());
+        assert!(std::panic::catch_unwind(|| convert_base_generic(&[1u16], 65537, 10)).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::error::ConvertError;
+use crate::optimized::{convert_base, Converter};
+use crate::utils::{digits_to_string, parse_default_symbols, significant_len, string_to_digits_auto, validate_digits, MAX_BASE};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+/// A number stored as digits together with the base they are written in
+///
+/// Digits are least significant first, like everywhere else in the crate.
+/// Construction validates the base and every digit, so a `Number` is
+/// always safe to convert. With the `serde` feature it can be serialized,
+/// and deserialization applies the same validation.
+///
+/// Equality and hashing look at the value and the base, not the raw
+/// digits: zero limbs at the most significant end are ignored, so `[5]`
+/// and `[5, 0]` in base 10 are equal, as are `[]` and `[0]`. The base is
+/// part of the identity, so 255 in base 10 and `ff` in base 16 are
+/// different keys; `value_eq` compares values across bases. `digits()`
+/// still returns the digits as given.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawNumber"))]
+pub struct Number {
+    digits: Vec<u64>,
+    base: u64,
+}
+
+impl Number {
+    /// Create a number, checking that the base is supported and every digit is below it
+    pub fn new(digits: Vec<u64>, base: u64) -> Result<Self, ConvertError> {
+        validate_digits(&digits, base)?;
+        Ok(Number { digits, base })
+    }
+
+    pub fn digits(&self) -> &[u64] {
+        &self.digits
+    }
+
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Whether both numbers have the same value, whatever their bases
+    ///
+    /// Converts `other` to this number's base when the bases differ.
+    pub fn value_eq(&self, other: &Number) -> bool {
+        if self.base == other.base {
+            return self.significant_digits() == other.significant_digits();
+        }
+        let converted = convert_base(&other.digits, other.base, self.base);
+        self.significant_digits() == &converted[..significant_len(&converted)]
+    }
+
+    /// The digits without zero limbs at the most significant end
+    fn significant_digits(&self) -> &[u64] {
+        &self.digits[..significant_len(&self.digits)]
+    }
+
+    /// Parse a string written in `base`
+    ///
+    /// Uses the same `0-9a-zA-Z` symbols as `string_to_digits`.
+    pub fn from_str_radix(s: &str, base: u64) -> Result<Self, ConvertError> {
+        if !(2..=MAX_BASE).contains(&base) {
+            return Err(ConvertError::BaseOutOfRange { base });
+        }
+        Number::new(parse_default_symbols(s, base)?, base)
+    }
+
+    /// Convert to another base with `convert_base`
+    ///
+    /// The digits were validated by `new`, so they aren't scanned again.
+    pub fn convert_to(&self, base: u64) -> Number {
+        if !(2..=MAX_BASE).contains(&base) {
+            panic!("Bases must be between 2 and {}", MAX_BASE);
+        }
+
+        let mut digits = Vec::new();
+        Converter::new().convert_validated_into(&self.digits, self.base, base, &mut digits);
+        Number { digits, base }
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base && self.significant_digits() == other.significant_digits()
+    }
+}
+
+impl Eq for Number {}
+
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.base.hash(state);
+        self.significant_digits().hash(state);
+    }
+}
+
+/// Digits checked once against their base, for converting to several bases
+///
+/// `new` scans every digit; `convert_to` then goes straight to the
+/// conversion without re-validating. An unsupported target base is
+/// returned as an error, so converting from a `ValidatedDigits` never
+/// panics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatedDigits {
+    digits: Vec<u64>,
+    base: u64,
+}
+
+impl ValidatedDigits {
+    pub fn new(digits: Vec<u64>, base: u64) -> Result<Self, ConvertError> {
+        let Number { digits, base } = Number::new(digits, base)?;
+        Ok(ValidatedDigits { digits, base })
+    }
+
+    pub fn digits(&self) -> &[u64] {
+        &self.digits
+    }
+
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// Convert to `to_base`, skipping per-call digit validation
+    pub fn convert_to(&self, to_base: u64) -> Result<Vec<u64>, ConvertError> {
+        if !(2..=MAX_BASE).contains(&to_base) {
+            return Err(ConvertError::BaseOutOfRange { base: to_base });
+        }
+
+        let mut result = Vec::new();
+        Converter::new().convert_validated_into(&self.digits, self.base, to_base, &mut result);
+        Ok(result)
+    }
+}
+
+/// Writes the digits with `digits_to_string`, without a radix prefix
+///
+/// Bases above 62 use the bracketed decimal form, e.g. `[1][999]`.
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&digits_to_string(&self.digits, self.base))
+    }
+}
+
+/// Parses base 10 by default, or base 16/2/8 with a `0x`/`0b`/`0o` prefix
+impl FromStr for Number {
+    type Err = ConvertError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, base) = string_to_digits_auto(s)?;
+        Number::new(digits, base)
+    }
+}
+
+/// Unvalidated form used as the deserialization target
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawNumber {
+    digits: Vec<u64>,
+    base: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawNumber> for Number {
+    type Error = ConvertError;
+
+    fn try_from(raw: RawNumber) -> Result<Self, Self::Error> {
+        Number::new(raw.digits, raw.base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_validates() {
+        assert!(Number::new(vec![9, 9], 10).is_ok());
+        assert_eq!(
+            Number::new(vec![10], 10),
+            Err(ConvertError::InvalidDigit { digit: 10, base: 10, index: 0 })
+        );
+        assert_eq!(Number::new(vec![0], 1), Err(ConvertError::BaseOutOfRange { base: 1 }));
+    }
+
+    #[test]
+    fn test_convert_to() {
+        let n = Number::new(vec![5, 5, 2], 10).unwrap();
+        let hex = n.convert_to(16);
+        assert_eq!(hex.digits(), &[15, 15]);
+        assert_eq!(hex.base(), 16);
+        assert_eq!(hex.convert_to(10), n);
+    }
+
+    #[test]
+    fn test_eq_ignores_leading_zero_limbs() {
+        use std::collections::HashSet;
+
+        let a = Number::new(vec![5], 10).unwrap();
+        let b = Number::new(vec![5, 0, 0], 10).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(Number::new(vec![], 10).unwrap(), Number::new(vec![0, 0], 10).unwrap());
+        assert_ne!(a, Number::new(vec![0, 5], 10).unwrap());
+
+        let set: HashSet<Number> = [a.clone(), b, Number::new(vec![0], 10).unwrap(), Number::new(vec![], 10).unwrap()]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Number::new(vec![5, 0], 10).unwrap()));
+    }
+
+    #[test]
+    fn test_base_is_part_of_identity() {
+        let decimal = Number::new(vec![5, 5, 2], 10).unwrap();
+        let hex = Number::new(vec![15, 15, 0], 16).unwrap();
+        assert_ne!(decimal, hex);
+        assert!(decimal.value_eq(&hex));
+        assert!(hex.value_eq(&decimal));
+        assert!(!decimal.value_eq(&Number::new(vec![14, 15], 16).unwrap()));
+        assert!(Number::new(vec![], 10).unwrap().value_eq(&Number::new(vec![0], 16).unwrap()));
+    }
+
+    #[test]
+    fn test_validated_digits() {
+        assert_eq!(
+            ValidatedDigits::new(vec![3, 10], 10),
+            Err(ConvertError::InvalidDigit { digit: 10, base: 10, index: 1 })
+        );
+        assert_eq!(ValidatedDigits::new(vec![1], MAX_BASE + 1), Err(ConvertError::BaseOutOfRange { base: MAX_BASE + 1 }));
+
+        let validated = ValidatedDigits::new(vec![5, 5, 2], 10).unwrap();
+        assert_eq!(validated.convert_to(16), Ok(vec![15, 15]));
+        assert_eq!(validated.convert_to(2), Ok(crate::convert_base(&[5, 5, 2], 10, 2)));
+        assert_eq!(validated.convert_to(10), Ok(vec![5, 5, 2]));
+        assert_eq!(validated.convert_to(1), Err(ConvertError::BaseOutOfRange { base: 1 }));
+        assert_eq!(validated.convert_to(1), Err(ConvertError::BaseOutOfRange { base: 1 }));
+    }
+
+    #[test]
+    fn test_display_and_parse() {
+        let n: Number = "0xdeadbeef".parse().unwrap();
+        assert_eq!(n.base(), 16);
+        assert_eq!(n.to_string(), "deadbeef");
+        assert_eq!(n.convert_to(10).to_string(), "3735928559");
+
+        let n: Number = "3735928559".parse().unwrap();
+        assert_eq!(n.base(), 10);
+        assert_eq!(n.convert_to(16).to_string(), "deadbeef");
+
+        assert_eq!(Number::from_str_radix("deadbeef", 16).unwrap().to_string(), "deadbeef");
+        assert!("12a".parse::<Number>().is_err());
+    }
+
+    #[test]
+    fn test_display_large_base() {
+        let n = Number::new(vec![999, 1], 1000).unwrap();
+        assert_eq!(n.to_string(), "[1][999]");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let n = Number::new(vec![15, 15], 16).unwrap();
+        let value = serde_json::to_value(&n).unwrap();
+        assert_eq!(value, serde_json::json!({ "digits": [15, 15], "base": 16 }));
+        assert_eq!(serde_json::from_value::<Number>(value).unwrap(), n);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_rejects_invalid_digit() {
+        let value = serde_json::json!({ "digits": [16], "base": 16 });
+        let err = serde_json::from_value::<Number>(value).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid digit 16 for base 16");
+    }
+}
@@ -1,276 +1,1762 @@
-use crate::utils::{is_power_of_two, log2_of_power_of_two};
-use std::collections::HashMap;
-use std::sync::Mutex;
+use crate::divide_conquer;
+use crate::error::ConvertError;
+use crate::utils::{check_digits, estimate_output_len, is_perfect_power, is_power_of_two, is_zero, log2_of_power_of_two, significant_len, MAX_BASE};
+use alloc::vec;
+use alloc::vec::Vec;
 
-// Cache for prime factorization results
-static FACTORIZATION_CACHE: Mutex<Option<HashMap<u64, Vec<(u64, u32)>>>> = Mutex::new(None);
+/// Inputs at least this long use the divide-and-conquer algorithm
+const DIVIDE_AND_CONQUER_THRESHOLD: usize = 64;
 
-/// Get cached factorization or compute and cache it
-fn get_factorization(n: u64) -> Vec<(u64, u32)> {
-    let mut cache = FACTORIZATION_CACHE.lock().unwrap();
-    if cache.is_none() {
-        *cache = Some(HashMap::new());
-    }
+/// Inputs longer than this, converting to a smaller base, use the chunked loop
+const CHUNKED_THRESHOLD: usize = 2000;
 
-    let cache_map = cache.as_mut().unwrap();
-    if let Some(result) = cache_map.get(&n) {
-        result.clone()
-    } else {
-        let computed = prime_factorization(n);
-        cache_map.insert(n, computed.clone());
-        computed
+/// Digits per chunk in the chunked loop
+const CHUNK_SIZE: usize = 64;
+
+/// 10^9, the largest power of ten below `MAX_BASE`
+const DECIMAL_SUPER_BASE: u64 = 1_000_000_000;
+const DECIMAL_SUPER_DIGITS: usize = 9;
+
+/// Decimal inputs or outputs at least this long are packed into base 10^9
+/// on the general path; shorter ones mostly take the u128/u256 paths anyway
+const DECIMAL_SUPER_DIGIT_THRESHOLD: usize = 64;
+
+pub fn convert_base(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    Converter::new().convert(digits, from_base, to_base)
+}
+
+/// The path `convert_base` took for one conversion
+///
+/// Reported by `convert_base_explained`. The variants follow the dispatch
+/// order of `convert_base`: earlier checks win, so a short aligned input
+/// still reports `U128FastPath`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// The input was zero, which always converts to `[0]`
+    Zero,
+    /// Both bases were equal and the digits were copied
+    SameBase,
+    /// Both bases were powers of two and bits were repacked
+    PowerOfTwo,
+    /// The value fit in a u128
+    U128FastPath,
+    /// The value fit in 256 bits
+    U256FastPath,
+    /// `from_base^exp_a == to_base^exp_b`, converted group by group
+    Aligned { exp_a: usize, exp_b: usize },
+    /// Long input, split recursively with Karatsuba multiplication
+    DivideAndConquer,
+    /// Repeated division, processed in cache-sized chunks
+    GeneralChunked,
+    /// Repeated division
+    General,
+}
+
+/// `convert_base`, also reporting which strategy produced the result
+///
+/// Useful to see which path a workload hits; the digits are exactly what
+/// `convert_base` returns.
+pub fn convert_base_explained(digits: &[u64], from_base: u64, to_base: u64) -> (Vec<u64>, Strategy) {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
     }
+
+    let mut result = Vec::new();
+    let strategy = Converter::new().convert_validated_into(digits, from_base, to_base, &mut result);
+    (result, strategy)
 }
 
-pub fn convert_base(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
-    if from_base < 2 || from_base > 65536 || to_base < 2 || to_base > 65536 {
-        panic!("Bases must be between 2 and 65536");
+/// `convert_base` without the final removal of zero limbs
+///
+/// Returns the limbs exactly as the selected strategy computed them. The
+/// power-of-two and aligned paths work in whole blocks, so their output
+/// can end in zero limbs that `convert_base` would drop: 8 binary digits
+/// to base 16 always give two hex digits. Long conversions to base 10
+/// likewise come out in groups of nine digits. Equal bases return the input
+/// unchanged and an empty input gives `[0]`. Callers opting out of
+/// trimming must normalize the result themselves before comparing it or
+/// passing it to code that expects canonical digits.
+pub fn convert_base_no_trim(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
     }
 
     if from_base == to_base {
         return digits.to_vec();
     }
-
-    if digits.is_empty() || (digits.len() == 1 && digits[0] == 0) {
+    if digits.is_empty() {
         return vec![0];
     }
 
-    for &digit in digits {
-        if digit >= from_base {
-            panic!("Invalid digit {} for base {}", digit, from_base);
+    let mut result = Vec::new();
+    Converter::new().convert_untrimmed_into(digits, from_base, to_base, &mut result);
+    result
+}
+
+/// Like `convert_base`, but reports invalid bases and digits as errors
+///
+/// Returns `ConvertError::BaseOutOfRange` or `ConvertError::InvalidDigit`
+/// where `convert_base` would panic.
+pub fn try_convert_base(digits: &[u64], from_base: u64, to_base: u64) -> Result<Vec<u64>, ConvertError> {
+    for base in [from_base, to_base] {
+        if !(2..=MAX_BASE).contains(&base) {
+            return Err(ConvertError::BaseOutOfRange { base });
         }
     }
+    check_digits(digits, from_base)?;
 
-    // Strategy 1: Both bases are powers of two - use bit operations (6.17x speedup)
-    if is_power_of_two(from_base) && is_power_of_two(to_base) {
-        return convert_power_of_two_optimized(digits, from_base, to_base);
+    Ok(convert_base(digits, from_base, to_base))
+}
+
+/// `convert_base` without the scan that checks every digit
+///
+/// The caller must guarantee that every digit is below `from_base`, for
+/// example by having run `validate_digits` once on data that is converted
+/// many times. Debug builds still assert it. In release builds an invalid
+/// digit is not caught and the result is unspecified, though never
+/// memory-unsafe. The bases are still checked, which costs O(1).
+pub fn convert_base_unchecked(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
     }
+    debug_assert!(
+        check_digits(digits, from_base).is_ok(),
+        "convert_base_unchecked called with a digit not below {}",
+        from_base
+    );
 
-    // Strategy 2: Try small number optimization (u128 fast path) (2.96x speedup)
-    if let Some(num) = try_convert_to_u128(digits, from_base) {
-        return convert_from_u128(num, to_base);
+    let mut result = Vec::new();
+    Converter::new().convert_validated_into(digits, from_base, to_base, &mut result);
+    result
+}
+
+/// Like `try_convert_base`, but refuses outputs longer than `max_out_len`
+///
+/// The check runs `estimate_output_len` on the input's significant digits
+/// before any conversion work, so an oversized request is rejected in O(n)
+/// without allocating. The estimate is an upper bound a couple of digits
+/// above the exact length (more without `std`), and `OutputTooLarge`
+/// reports it as `needed`; leave that much headroom when the cap must
+/// admit results of exactly `max_out_len` digits.
+pub fn convert_base_capped(
+    digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    max_out_len: usize,
+) -> Result<Vec<u64>, ConvertError> {
+    for base in [from_base, to_base] {
+        if !(2..=MAX_BASE).contains(&base) {
+            return Err(ConvertError::BaseOutOfRange { base });
+        }
     }
+    check_digits(digits, from_base)?;
 
-    // Strategy 3: Check for aligned bases (n^a = m^b) (2.97x speedup)
-    if let Some((exp_a, exp_b)) = find_aligned_exponents(from_base, to_base) {
-        return convert_aligned_bases(digits, from_base, to_base, exp_a, exp_b);
+    let needed = if from_base == to_base && !is_zero(digits) {
+        digits.len()
+    } else {
+        estimate_output_len(significant_len(digits), from_base, to_base)
+    };
+    if needed > max_out_len {
+        return Err(ConvertError::OutputTooLarge { needed, max: max_out_len });
     }
 
-    // Strategy 4: General case - use optimized tricks for better performance
-    convert_general_optimized_tricks(digits, from_base, to_base)
+    Ok(convert_base(digits, from_base, to_base))
 }
 
-fn convert_power_of_two_optimized(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
-    let from_shift = log2_of_power_of_two(from_base);
-    let to_shift = log2_of_power_of_two(to_base);
+/// Convert into a caller-provided buffer instead of returning a new `Vec`
+///
+/// `out` is cleared first and its existing capacity is reused, so no
+/// output allocation happens when it is already large enough. The result
+/// is identical to `convert_base`. The general path still allocates its
+/// scratch vectors; use `Converter::convert_into` to reuse those as well.
+pub fn convert_base_into(digits: &[u64], from_base: u64, to_base: u64, out: &mut Vec<u64>) {
+    Converter::new().convert_into(digits, from_base, to_base, out)
+}
 
-    let total_bits = if digits.is_empty() { 0 } else {
-        let msb = digits[digits.len() - 1];
-        let msb_bits = 64 - msb.leading_zeros() as u32;
-        ((digits.len() - 1) as u32) * from_shift + msb_bits
-    };
+/// Convert, overwriting `digits` with the result
+///
+/// When the output can never be longer than the input, the conversion runs
+/// in the vector's own storage and allocates nothing:
+///
+/// - equal bases, where the digits are left as they are
+/// - powers of two where the target digit is at least as wide, like 2 to 16
+/// - aligned bases where a group shrinks, like 9 to 27 (three digits to two)
+///
+/// Zero becomes `[0]` in place as well. Every other pair converts with
+/// `convert_base` and replaces the vector. The result is identical to
+/// `convert_base` in all cases.
+pub fn convert_base_in_place(digits: &mut Vec<u64>, from_base: u64, to_base: u64) {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    for &digit in digits.iter() {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
+
+    if is_zero(digits) {
+        digits.truncate(1);
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        return;
+    }
+    if from_base == to_base {
+        return;
+    }
+
+    if is_power_of_two(from_base) && is_power_of_two(to_base) && to_base > from_base {
+        let len = pack_bits_in_place(digits, log2_of_power_of_two(from_base), log2_of_power_of_two(to_base));
+        digits.truncate(len);
+    } else if let Some((exp_a, exp_b)) = aligned_exponents(from_base, to_base).filter(|&(a, b)| a >= b) {
+        let len = regroup_in_place(digits, &aligned_from_powers(from_base, exp_a), to_base, exp_b);
+        digits.truncate(len);
+    } else {
+        *digits = convert_base(digits, from_base, to_base);
+        return;
+    }
 
-    let output_len = if total_bits == 0 { 1 } else { (total_bits + to_shift - 1) / to_shift };
-    let mut result = Vec::with_capacity(output_len as usize);
+    digits.truncate(significant_len(digits).max(1));
+}
 
+/// Repack bits into wider digits in the same buffer, returning the new length
+///
+/// Output digit `j` is written only after input digit `j` has been read,
+/// because each output digit holds at least as many bits as an input digit.
+fn pack_bits_in_place(digits: &mut [u64], from_shift: u32, to_shift: u32) -> usize {
     let mut buffer = 0u64;
     let mut buffer_bits = 0u32;
+    let mut written = 0;
 
-    for &digit in digits {
-        buffer |= digit << buffer_bits;
+    for i in 0..digits.len() {
+        buffer |= digits[i] << buffer_bits;
         buffer_bits += from_shift;
 
         while buffer_bits >= to_shift {
-            result.push(buffer & ((1u64 << to_shift) - 1));
+            digits[written] = buffer & ((1u64 << to_shift) - 1);
+            written += 1;
             buffer >>= to_shift;
             buffer_bits -= to_shift;
         }
     }
 
     if buffer_bits > 0 {
-        result.push(buffer);
-    }
-
-    while result.len() > 1 && result.last() == Some(&0) {
-        result.pop();
+        digits[written] = buffer;
+        written += 1;
     }
 
-    result
+    written
 }
 
-fn try_convert_to_u128(digits: &[u64], base: u64) -> Option<u128> {
-    // Quick size check: if digits are too many, likely won't fit in u128
-    if digits.len() > 20 {
-        return None;
+/// `convert_aligned_bases` in the input's own buffer, for groups that shrink
+///
+/// Each group's value is read in full before its `exp_b` output digits are
+/// written, and those land at or before the group's own position.
+fn regroup_in_place(digits: &mut [u64], from_powers: &[u128], to_base: u64, exp_b: usize) -> usize {
+    let exp_a = from_powers.len();
+    let groups = digits.len().div_ceil(exp_a);
+
+    for group in 0..groups {
+        let start = group * exp_a;
+        let end = (start + exp_a).min(digits.len());
+        let mut value = 0u128;
+        for (i, &digit) in digits[start..end].iter().enumerate() {
+            value += digit as u128 * from_powers[i];
+        }
+
+        // A short last group of m digits needs at most m output digits,
+        // which always fit before the end of the buffer
+        let out_end = ((group + 1) * exp_b).min(digits.len());
+        for slot in &mut digits[group * exp_b..out_end] {
+            *slot = (value % to_base as u128) as u64;
+            value /= to_base as u128;
+        }
     }
 
-    let mut result = 0u128;
-    let base_u128 = base as u128;
+    (groups * exp_b).min(digits.len())
+}
 
-    // Process from most significant to least significant to avoid overflow
-    for &digit in digits.iter().rev() {
-        let digit_u128 = digit as u128;
+/// Convert into a stack array of `M` digits, returning it and the used length
+///
+/// Nothing is allocated: values that fit in a u128 go through the u128
+/// fast path, and anything larger is divided down in a stack copy of the
+/// input. Digits past the returned length are zero.
+///
+/// The bases are runtime values, so the capacity can't be checked at
+/// compile time. `M` must be at least the output length, which is at most
+/// `ceil(N * log2(from_base) / log2(to_base))` and never below 1; for
+/// example 4 binary digits need 1 hex digit, and 1 base-256 digit needs 4
+/// base-4 digits. Panics if the result doesn't fit in `M` digits.
+pub fn convert_base_fixed<const N: usize, const M: usize>(
+    digits: &[u64; N],
+    from_base: u64,
+    to_base: u64,
+) -> ([u64; M], usize) {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
+        }
+    }
 
-        // Check for overflow before multiplication
-        if result > u128::MAX / base_u128 {
-            return None;
+    let mut out = [0u64; M];
+    let mut len = 0;
+    let mut push = |digit: u64| {
+        if len == M {
+            panic!("Output needs more than {} digits", M);
         }
-        result *= base_u128;
+        out[len] = digit;
+        len += 1;
+    };
 
-        // Check for overflow before addition
-        if result > u128::MAX - digit_u128 {
-            return None;
+    if let Some(mut num) = try_convert_to_u128(digits, from_base) {
+        loop {
+            push((num % to_base as u128) as u64);
+            num /= to_base as u128;
+            if num == 0 {
+                break;
+            }
+        }
+    } else {
+        // Repeated division of a stack copy, most significant digit first
+        let mut current = *digits;
+        let mut top = significant_len(&current);
+        while top > 0 {
+            let mut carry = 0u64;
+            for digit in current[..top].iter_mut().rev() {
+                let value = carry_step(carry, from_base, *digit);
+                *digit = value / to_base;
+                carry = value % to_base;
+            }
+            push(carry);
+            top = significant_len(&current[..top]);
         }
-        result += digit_u128;
     }
 
-    Some(result)
+    (out, len)
 }
 
-fn convert_from_u128(mut num: u128, base: u64) -> Vec<u64> {
-    if num == 0 {
-        return vec![0];
+/// Convert, then pad with zeros at the most significant end to `min_len` digits
+///
+/// Results that are already at least `min_len` digits long are returned
+/// unchanged. Handy for fixed-width output: `[255]` in base 10 padded to 4
+/// hex digits is `[15, 15, 0, 0]`, which `digits_to_string` writes as
+/// `"00ff"`.
+pub fn convert_base_padded(digits: &[u64], from_base: u64, to_base: u64, min_len: usize) -> Vec<u64> {
+    let mut result = convert_base(digits, from_base, to_base);
+    if result.len() < min_len {
+        result.resize(min_len, 0);
     }
+    result
+}
 
-    let base_u128 = base as u128;
-    let mut result = Vec::new();
+/// Repack `from_bits`-bit values into `to_bits`-bit values
+///
+/// The same operation as converting from base `2^from_bits` to base
+/// `2^to_bits`, framed in bit widths: values are least significant first,
+/// and the bits of all values, laid end to end, are cut into the new
+/// width. The result matches `convert_base` between those bases, including
+/// its trimming, so zero is `[0]`. Widths up to 32 bits delegate to the
+/// power-of-two path; wider ones, up to 64, use a u128 bit buffer. Panics
+/// if a width is outside 1..=64 or a value doesn't fit in `from_bits`.
+///
+/// ```
+/// use fast_base_convert::repack_bits;
+///
+/// // 0xABC, 0x123 as 12-bit values are the bytes 0xBC, 0x3A, 0x12
+/// assert_eq!(repack_bits(&[0xABC, 0x123], 12, 8), vec![0xBC, 0x3A, 0x12]);
+/// ```
+pub fn repack_bits(values: &[u64], from_bits: u32, to_bits: u32) -> Vec<u64> {
+    for bits in [from_bits, to_bits] {
+        if !(1..=64).contains(&bits) {
+            panic!("Bit widths must be between 1 and 64");
+        }
+    }
+    if from_bits <= 32 && to_bits <= 32 {
+        return convert_base(values, 1 << from_bits, 1 << to_bits);
+    }
 
-    while num > 0 {
-        result.push((num % base_u128) as u64);
-        num /= base_u128;
+    for &value in values {
+        if from_bits < 64 && value >> from_bits != 0 {
+            panic!("Value {} does not fit in {} bits", value, from_bits);
+        }
+    }
+    if is_zero(values) {
+        return vec![0];
+    }
+    if from_bits == to_bits {
+        return values.to_vec();
     }
 
+    // Fewer than to_bits bits stay buffered between values, so the buffer
+    // needs at most 63 + 64 bits
+    let mask = u64::MAX >> (64 - to_bits);
+    let mut result = Vec::with_capacity((values.len() * from_bits as usize).div_ceil(to_bits as usize));
+    let mut buffer = 0u128;
+    let mut buffer_bits = 0u32;
+    for &value in values {
+        buffer |= (value as u128) << buffer_bits;
+        buffer_bits += from_bits;
+        while buffer_bits >= to_bits {
+            result.push(buffer as u64 & mask);
+            buffer >>= to_bits;
+            buffer_bits -= to_bits;
+        }
+    }
+    if buffer_bits > 0 {
+        result.push(buffer as u64);
+    }
+
+    result.truncate(significant_len(&result).max(1));
     result
 }
 
-fn find_aligned_exponents(from_base: u64, to_base: u64) -> Option<(usize, usize)> {
-    // Quick check for common aligned bases
-    match (from_base, to_base) {
-        // Base 4 and 16: 4^2 = 16
-        (4, 16) | (16, 4) => return Some((2, 1)),
-        // Base 8 and 64: 8^2 = 64
-        (8, 64) | (64, 8) => return Some((2, 1)),
-        // Base 9 and 27: 9^1.5 = 27 (not integer powers, skip)
-        // Base 27 and 3: 27 = 3^3
-        (27, 3) | (3, 27) => return Some((1, 3)),
-        // Base 16 and 2: 16 = 2^4
-        (16, 2) | (2, 16) => return Some((1, 4)),
-        // Base 32 and 2: 32 = 2^5
-        (32, 2) | (2, 32) => return Some((1, 5)),
-        // Base 64 and 4: 64 = 4^3 * 4^0 (not perfect, skip)
-        _ => {}
+/// Convert to exactly `exact_len` digits, for fixed-size encodings
+///
+/// Shorter results are padded with zeros at the most significant end, as
+/// in `convert_base_padded`; a value that needs more than `exact_len`
+/// digits is `OverflowsFixedWidth` instead of being returned longer. Zero
+/// fits any width, `exact_len == 0` included, and comes back as
+/// `exact_len` zeros. Bases and digits are validated like
+/// `try_convert_base`.
+pub fn convert_base_exact(
+    digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    exact_len: usize,
+) -> Result<Vec<u64>, ConvertError> {
+    let mut result = try_convert_base(digits, from_base, to_base)?;
+    if is_zero(&result) {
+        result.clear();
+    }
+    if result.len() > exact_len {
+        return Err(ConvertError::OverflowsFixedWidth { len: result.len(), width: exact_len });
     }
+    result.resize(exact_len, 0);
+    Ok(result)
+}
 
-    // General case - use prime factorization
-    const MAX_EXPONENT: usize = 10;
+/// Convert, reporting the input's leading zero limbs instead of dropping them
+///
+/// Returns how many zero limbs sit above the most significant nonzero
+/// digit (at the end of the slice, since digits are least significant
+/// first) together with the conversion of the rest. Encodings such as
+/// base58check write one zero symbol per leading zero byte, so callers
+/// append that many zeros in the target base. Zero has no significant
+/// part: `[0, 0]` gives `(2, [])` and the empty input `(0, [])`.
+pub fn convert_base_with_leading(digits: &[u64], from_base: u64, to_base: u64) -> (usize, Vec<u64>) {
+    let significant = significant_len(digits);
+    let leading_zeros = digits.len() - significant;
+    if significant == 0 {
+        // Still validates the bases
+        convert_base(&[], from_base, to_base);
+        return (leading_zeros, Vec::new());
+    }
+    (leading_zeros, convert_base(&digits[..significant], from_base, to_base))
+}
 
-    let from_factors = get_factorization(from_base);
-    let to_factors = get_factorization(to_base);
+/// Convert most-significant-first digits, returning most-significant-first
+///
+/// `convert_base` treats `digits[0]` as the least significant digit; this
+/// variant uses reading order instead, so `[1, 2, 3]` in base 10 is 123.
+/// The digits are reversed at the boundaries around `convert_base`.
+pub fn convert_base_be(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    let little_endian: Vec<u64> = digits.iter().rev().copied().collect();
+    let mut result = convert_base(&little_endian, from_base, to_base);
+    result.reverse();
+    result
+}
 
-    if from_factors != to_factors {
-        return None;
+/// The smallest base in which the number has at most `max_digits` digits
+///
+/// The digit count only shrinks as the base grows, so this binary-searches
+/// the bases from 2 to `MAX_BASE` with `count_digits`, about 32 probes.
+/// Zero fits in one digit of any base. When even `MAX_BASE` needs more
+/// than `max_digits` digits the result is `OutputTooLarge` with that count
+/// as `needed`. Invalid bases and digits are reported like
+/// `try_convert_base`.
+///
+/// ```
+/// use fast_base_convert::min_base_for_digits;
+///
+/// // 65535 is "ffff" in hex but needs five digits in base 15
+/// assert_eq!(min_base_for_digits(&[5, 3, 5, 5, 6], 10, 4), Ok(16));
+/// ```
+pub fn min_base_for_digits(digits: &[u64], from_base: u64, max_digits: usize) -> Result<u64, ConvertError> {
+    if !(2..=MAX_BASE).contains(&from_base) {
+        return Err(ConvertError::BaseOutOfRange { base: from_base });
     }
+    check_digits(digits, from_base)?;
 
-    // Try small exponents first (most common cases)
-    for a in 1..=MAX_EXPONENT {
-        let from_power = from_base.pow(a as u32);
+    // count_digits keeps leading zeros for equal bases, so drop them first
+    let digits = &digits[..significant_len(digits)];
+    let needed = count_digits(digits, from_base, MAX_BASE);
+    if needed > max_digits {
+        return Err(ConvertError::OutputTooLarge { needed, max: max_digits });
+    }
 
-        for b in 1..=MAX_EXPONENT {
-            if to_base.pow(b as u32) == from_power {
-                return Some((a, b));
-            }
+    let (mut lo, mut hi) = (2, MAX_BASE);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if count_digits(digits, from_base, mid) <= max_digits {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Ok(lo)
+}
+
+/// The length `convert_base` would return, without building the output
+///
+/// Base pairs with a closed form cost O(1) after skipping leading zeros:
+///
+/// - equal bases, where the input is returned as is
+/// - two powers of two, from the bit length of the input
+/// - aligned bases (`from_base^a == to_base^b`, like 9 and 27), where each
+///   full group of `a` digits becomes `b` digits and only the top group is
+///   converted
+///
+/// Inputs that fit in a `u128` are counted in O(n). Every other pair runs
+/// the long division on a scratch copy of the input, still O(n^2), but it
+/// divides by the largest power of `to_base` that fits in a limb and never
+/// stores a digit. Zero, in any form, counts as one digit.
+pub fn count_digits(digits: &[u64], from_base: u64, to_base: u64) -> usize {
+    if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    for &digit in digits {
+        if digit >= from_base {
+            panic!("Invalid digit {} for base {}", digit, from_base);
         }
     }
 
-    None
+    if is_zero(digits) {
+        return 1;
+    }
+    if from_base == to_base {
+        return digits.len();
+    }
+
+    let digits = &digits[..significant_len(digits)];
+    let top = digits[digits.len() - 1];
+
+    if is_power_of_two(from_base) && is_power_of_two(to_base) {
+        let from_shift = log2_of_power_of_two(from_base) as usize;
+        let to_shift = log2_of_power_of_two(to_base) as usize;
+        let total_bits = (digits.len() - 1) * from_shift + (64 - top.leading_zeros()) as usize;
+        return total_bits.div_ceil(to_shift);
+    }
+
+    if let Some(num) = try_convert_to_u128(digits, from_base) {
+        return count_u128_digits(num, to_base);
+    }
+
+    if let Some((exp_a, exp_b)) = aligned_exponents(from_base, to_base) {
+        // The groups below the top one are full and convert to exactly
+        // exp_b digits each; the top group is below from_base^exp_a
+        let full_groups = (digits.len() - 1) / exp_a;
+        let top_group = &digits[full_groups * exp_a..];
+        let from_powers = aligned_from_powers(from_base, exp_a);
+        let value = top_group.iter().zip(&from_powers).map(|(&digit, &power)| digit as u128 * power).sum();
+        return full_groups * exp_b + count_u128_digits(value, to_base);
+    }
+
+    count_by_division(digits, from_base, to_base)
 }
 
-fn prime_factorization(mut n: u64) -> Vec<(u64, u32)> {
-    let mut factors = Vec::new();
+/// Digits of a nonzero `num` in `base`
+fn count_u128_digits(mut num: u128, base: u64) -> usize {
+    let mut count = 0;
+    while num > 0 {
+        num /= base as u128;
+        count += 1;
+    }
+    count
+}
 
-    if n % 2 == 0 {
-        let count = n.trailing_zeros() as u32;
-        factors.push((2, count));
-        n >>= count;
+/// Count digits by long division with `to_base^k` as the divisor
+///
+/// `k` is as large as keeps `divisor * from_base` within a u64, so the
+/// carry step cannot overflow. Every pass that leaves a nonzero quotient
+/// accounts for exactly `k` digits; the last remainder is counted digit by
+/// digit.
+fn count_by_division(digits: &[u64], from_base: u64, to_base: u64) -> usize {
+    let mut divisor = to_base;
+    let mut k = 1;
+    while let Some(next) = divisor.checked_mul(to_base).filter(|next| next.checked_mul(from_base).is_some()) {
+        divisor = next;
+        k += 1;
     }
 
-    let mut p = 3u64;
-    while p * p <= n {
-        if n % p == 0 {
-            let mut count = 0;
-            while n % p == 0 {
-                n /= p;
-                count += 1;
-            }
-            factors.push((p, count));
+    let mut current = digits.to_vec();
+    let mut count = 0;
+    loop {
+        let mut carry = 0u64;
+        for digit in current.iter_mut().rev() {
+            let value = carry_step(carry, from_base, *digit);
+            *digit = value / divisor;
+            carry = value % divisor;
         }
-        p += 2;
-    }
+        current.truncate(significant_len(&current));
 
-    if n > 1 {
-        factors.push((n, 1));
+        if current.is_empty() {
+            return count + count_u128_digits(carry as u128, to_base);
+        }
+        count += k;
     }
+}
 
-    factors.sort();
-    factors
+/// Strategy chosen once by `ConversionPlan::new`
+#[derive(Debug, Clone)]
+enum PlannedStrategy {
+    SameBase,
+    PowerOfTwo,
+    Aligned { from_powers: Vec<u128>, exp_b: usize },
+    General,
 }
 
-fn convert_aligned_bases(
-    digits: &[u64],
+/// Base analysis for one `(from_base, to_base)` pair, done once up front
+///
+/// `new` runs the power-of-two check, the aligned-exponent search and
+/// builds the aligned chunk powers, so `convert`
+/// goes straight to the chosen strategy. A plan is immutable after
+/// construction and `Send + Sync`, so one plan can be shared by every
+/// thread converting between the same bases. Results are identical to
+/// `convert_base`.
+#[derive(Debug, Clone)]
+pub struct ConversionPlan {
     from_base: u64,
     to_base: u64,
-    exp_a: usize,
-    exp_b: usize
-) -> Vec<u64> {
-    let mut from_powers = Vec::with_capacity(exp_a);
-    from_powers.push(1u128);
-    for i in 1..exp_a {
-        from_powers.push(from_powers[i-1] * from_base as u128);
+    strategy: PlannedStrategy,
+}
+
+impl ConversionPlan {
+    pub fn new(from_base: u64, to_base: u64) -> Self {
+        if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+            panic!("Bases must be between 2 and {}", MAX_BASE);
+        }
+
+        let strategy = if from_base == to_base {
+            PlannedStrategy::SameBase
+        } else if is_power_of_two(from_base) && is_power_of_two(to_base) {
+            PlannedStrategy::PowerOfTwo
+        } else if let Some((exp_a, exp_b)) = aligned_exponents(from_base, to_base) {
+            PlannedStrategy::Aligned {
+                from_powers: aligned_from_powers(from_base, exp_a),
+                exp_b,
+            }
+        } else {
+            PlannedStrategy::General
+        };
+
+        ConversionPlan { from_base, to_base, strategy }
     }
 
-    let output_len = (digits.len() + exp_a - 1) / exp_a * exp_b;
-    let mut result = Vec::with_capacity(output_len);
+    pub fn from_base(&self) -> u64 {
+        self.from_base
+    }
 
-    for chunk in digits.chunks(exp_a) {
-        let mut value = 0u128;
-        for (i, &digit) in chunk.iter().enumerate() {
-            value += digit as u128 * from_powers[i];
+    pub fn to_base(&self) -> u64 {
+        self.to_base
+    }
+
+    /// Convert `digits` with the planned strategy
+    pub fn convert(&self, digits: &[u64]) -> Vec<u64> {
+        let (from_base, to_base) = (self.from_base, self.to_base);
+
+        for &digit in digits {
+            if digit >= from_base {
+                panic!("Invalid digit {} for base {}", digit, from_base);
+            }
         }
 
-        for _ in 0..exp_b {
-            result.push((value % to_base as u128) as u64);
-            value /= to_base as u128;
+        let mut result = Vec::new();
+        if is_zero(digits) {
+            result.push(0);
+            return result;
         }
+
+        match &self.strategy {
+            // Copied as given, like `convert_base`, so no trim below
+            PlannedStrategy::SameBase => return digits.to_vec(),
+            PlannedStrategy::PowerOfTwo => convert_power_of_two_optimized(digits, from_base, to_base, &mut result),
+            strategy => {
+                // Same order as `Converter::convert_into`: the fixed-width
+                // paths win for short inputs whatever the bases are
+                if let Some(num) = try_convert_to_u128(digits, from_base) {
+                    convert_from_u128(num, to_base, &mut result);
+                } else if let Some(num) = try_convert_to_u256(digits, from_base) {
+                    convert_from_u256(num, to_base, &mut result);
+                } else if let PlannedStrategy::Aligned { from_powers, exp_b } = strategy {
+                    convert_aligned_bases(digits, to_base, from_powers, *exp_b, &mut result);
+                } else {
+                    convert_general(digits, from_base, to_base, &ConvertOptions::default(), &mut Vec::new(), &mut Vec::new(), &mut result);
+                }
+            }
+        }
+
+        result.truncate(significant_len(&result).max(1));
+        result
     }
+}
 
-    while result.len() > 1 && result.last() == Some(&0) {
-        result.pop();
+/// Convert many numbers between the same pair of bases
+///
+/// The bases are analyzed once with a `ConversionPlan`, which every input
+/// then shares, so the power-of-two check and the alignment search are
+/// not revisited per element. With the `rayon`
+/// feature the inputs are converted in parallel. Results are in input
+/// order and identical to calling `convert_base` on each.
+pub fn convert_base_many(inputs: &[Vec<u64>], from_base: u64, to_base: u64) -> Vec<Vec<u64>> {
+    let plan = ConversionPlan::new(from_base, to_base);
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        inputs.par_iter().map(|digits| plan.convert(digits)).collect()
     }
 
-    result
+    #[cfg(not(feature = "rayon"))]
+    {
+        inputs.iter().map(|digits| plan.convert(digits)).collect()
+    }
 }
 
+/// The two working vectors of the repeated-division path
+///
+/// `convert_base` allocates them on every call that reaches that path.
+/// Create one `ScratchBuffers` and pass it to `convert_base_with_scratch`
+/// to keep their capacity across calls instead. The buffers hold no state
+/// between conversions, so one value can serve any mix of base pairs.
+#[derive(Debug, Default)]
+pub struct ScratchBuffers {
+    current: Vec<u64>,
+    next_current: Vec<u64>,
+}
 
+impl ScratchBuffers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Size thresholds of the general path, for tuning to a machine or workload
+///
+/// `Default` gives the values `convert_base` uses. Inputs of at least
+/// `divide_and_conquer_threshold` digits go to divide-and-conquer; below
+/// that, inputs longer than `chunked_threshold` that convert to a smaller
+/// base are divided `chunk_size` digits at a time. With the defaults
+/// divide-and-conquer takes over long before the chunked loop would, so
+/// the chunk settings only matter with a higher divide-and-conquer
+/// threshold. The options never change results, only speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvertOptions {
+    pub divide_and_conquer_threshold: usize,
+    pub chunked_threshold: usize,
+    pub chunk_size: usize,
+}
 
-    #[test]
-    fn test_power_of_two_conversion() {
-        // 16 (2^4) to 8 (2^3)
-        let input = vec![0xA, 0xB, 0xC]; // 0xCBA = 3258
-        let result = convert_base(&input, 16, 8);
-        let baseline = crate::baseline::convert_base(&input, 16, 8);
-        // Verify match with baseline
-        assert_eq!(result, baseline);
-        // 3258 = 2*8^0 + 7*8^1 + 2*8^2 + 6*8^3 = [2, 7, 2, 6]
-        assert_eq!(result, vec![2, 7, 2, 6]);
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        ConvertOptions {
+            divide_and_conquer_threshold: DIVIDE_AND_CONQUER_THRESHOLD,
+            chunked_threshold: CHUNKED_THRESHOLD,
+            chunk_size: CHUNK_SIZE,
+        }
     }
+}
 
-    #[test]
-    fn test_small_number_optimization() {
-        // 12345 from base 10 to base 16
+/// `convert_base` with the general path's thresholds taken from `options`
+///
+/// The result is identical to `convert_base` for any options; a
+/// `chunk_size` of 0 is treated as 1.
+pub fn convert_base_with_options(digits: &[u64], from_base: u64, to_base: u64, options: &ConvertOptions) -> Vec<u64> {
+    Converter::with_options(*options).convert(digits, from_base, to_base)
+}
+
+/// `convert_base`, taking its working vectors from `scratch`
+///
+/// The result is identical to `convert_base`. Only the output is freshly
+/// allocated; the scratch vectors are cleared and reused.
+pub fn convert_base_with_scratch(digits: &[u64], from_base: u64, to_base: u64, scratch: &mut ScratchBuffers) -> Vec<u64> {
+    // Moving the vectors in and out keeps their capacity and allocates nothing
+    let mut converter = Converter { scratch: core::mem::take(scratch), options: ConvertOptions::default() };
+    let result = converter.convert(digits, from_base, to_base);
+    *scratch = converter.scratch;
+    result
+}
+
+/// Reusable converter that keeps its scratch buffers between calls
+///
+/// The general conversion path needs two working vectors. `convert_base`
+/// allocates them on every call; a `Converter` clears them instead, so
+/// their capacity is retained across conversions. It is meant to be
+/// owned by a single thread: create one per thread in hot loops rather
+/// than sharing one behind a lock.
+#[derive(Debug, Default)]
+pub struct Converter {
+    scratch: ScratchBuffers,
+    options: ConvertOptions,
+}
+
+impl Converter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A converter whose general path uses `options` instead of the defaults
+    pub fn with_options(options: ConvertOptions) -> Self {
+        Converter { scratch: ScratchBuffers::default(), options }
+    }
+
+    /// Convert `digits` from `from_base` to `to_base`, reusing internal buffers
+    pub fn convert(&mut self, digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+        let mut result = Vec::new();
+        self.convert_into(digits, from_base, to_base, &mut result);
+        result
+    }
+
+    /// Like `convert`, but writes the result into `out` after clearing it
+    pub fn convert_into(&mut self, digits: &[u64], from_base: u64, to_base: u64, out: &mut Vec<u64>) {
+        if !(2..=MAX_BASE).contains(&from_base) || !(2..=MAX_BASE).contains(&to_base) {
+            panic!("Bases must be between 2 and {}", MAX_BASE);
+        }
+
+        for &digit in digits {
+            if digit >= from_base {
+                panic!("Invalid digit {} for base {}", digit, from_base);
+            }
+        }
+
+        self.convert_validated_into(digits, from_base, to_base, out);
+    }
+
+    /// `convert_into` without the base and digit checks
+    ///
+    /// The caller guarantees both bases are in range and every digit is
+    /// below `from_base`. Returns the strategy that produced `out`.
+    pub(crate) fn convert_validated_into(&mut self, digits: &[u64], from_base: u64, to_base: u64, out: &mut Vec<u64>) -> Strategy {
+        let strategy = self.dispatch_into(digits, from_base, to_base, out);
+        #[cfg(feature = "stats")]
+        crate::stats::record(strategy);
+        strategy
+    }
+
+    /// Pick a strategy, convert, and trim the result
+    fn dispatch_into(&mut self, digits: &[u64], from_base: u64, to_base: u64, out: &mut Vec<u64>) -> Strategy {
+        out.clear();
+
+        // Every form of zero ([], [0], [0, 0], ...) comes back as exactly [0]
+        if is_zero(digits) {
+            out.push(0);
+            return Strategy::Zero;
+        }
+
+        if from_base == to_base {
+            out.extend_from_slice(digits);
+            return Strategy::SameBase;
+        }
+
+        let strategy = self.convert_untrimmed_into(digits, from_base, to_base, out);
+        out.truncate(significant_len(out).max(1));
+        strategy
+    }
+
+    /// Run the strategy dispatch, leaving any zero limbs at the top of `out`
+    ///
+    /// The power-of-two and aligned paths, and the general path when it
+    /// unpacks decimal output, emit whole blocks, so their raw output can
+    /// end in zeros; the other paths never produce them.
+    fn convert_untrimmed_into(&mut self, digits: &[u64], from_base: u64, to_base: u64, out: &mut Vec<u64>) -> Strategy {
+        // Strategy 1: Both bases are powers of two - use bit operations (6.17x speedup)
+        if is_power_of_two(from_base) && is_power_of_two(to_base) {
+            convert_power_of_two_optimized(digits, from_base, to_base, out);
+            return Strategy::PowerOfTwo;
+        }
+
+        // Strategy 2: Try small number optimization (u128 fast path) (2.96x speedup)
+        if let Some(num) = try_convert_to_u128(digits, from_base) {
+            convert_from_u128(num, to_base, out);
+            return Strategy::U128FastPath;
+        }
+
+        // Strategy 2b: Medium numbers that fit in 256 bits
+        if let Some(num) = try_convert_to_u256(digits, from_base) {
+            convert_from_u256(num, to_base, out);
+            return Strategy::U256FastPath;
+        }
+
+        // Strategy 3: Check for aligned bases (n^a = m^b) (2.97x speedup)
+        if let Some((exp_a, exp_b)) = aligned_exponents(from_base, to_base) {
+            let from_powers = aligned_from_powers(from_base, exp_a);
+            convert_aligned_bases(digits, to_base, &from_powers, exp_b, out);
+            return Strategy::Aligned { exp_a, exp_b };
+        }
+
+        // Strategy 4: General case - use optimized tricks for better performance
+        let scratch = &mut self.scratch;
+        convert_general(digits, from_base, to_base, &self.options, &mut scratch.current, &mut scratch.next_current, out)
+    }
+}
+
+/// The general path, with long decimal input or output packed into base 10^9
+///
+/// Base 10 and base 10^9 are aligned, so packing nine decimal digits into
+/// each limb and unpacking them again is linear, and the division or
+/// divide-and-conquer step then works on a ninth as many limbs. This pays
+/// most when base 10 is the target: 16 to 10 at 10000 digits drops from
+/// about 13 ms to 1 ms, while 10 to 16 gains only 3-10%. Unpacking emits nine
+/// digits per limb, so the output can end in zeros like the aligned path.
+fn convert_general(
+    digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    options: &ConvertOptions,
+    current: &mut Vec<u64>,
+    next_current: &mut Vec<u64>,
+    out: &mut Vec<u64>,
+) -> Strategy {
+    if digits.len() < DECIMAL_SUPER_DIGIT_THRESHOLD || (from_base != 10 && to_base != 10) {
+        return convert_general_optimized_tricks(digits, from_base, to_base, options, current, next_current, out);
+    }
+
+    let mut packed = Vec::new();
+    if from_base == 10 {
+        let from_powers = aligned_from_powers(10, DECIMAL_SUPER_DIGITS);
+        convert_aligned_bases(digits, DECIMAL_SUPER_BASE, &from_powers, 1, &mut packed);
+        packed.truncate(significant_len(&packed).max(1));
+        convert_general_optimized_tricks(&packed, DECIMAL_SUPER_BASE, to_base, options, current, next_current, out)
+    } else {
+        let strategy = convert_general_optimized_tricks(digits, from_base, DECIMAL_SUPER_BASE, options, current, next_current, &mut packed);
+        convert_aligned_bases(&packed, 10, &[1], DECIMAL_SUPER_DIGITS, out);
+        strategy
+    }
+}
+
+fn convert_power_of_two_optimized(digits: &[u64], from_base: u64, to_base: u64, result: &mut Vec<u64>) {
+    let from_shift = log2_of_power_of_two(from_base);
+    let to_shift = log2_of_power_of_two(to_base);
+
+    let total_bits = if digits.is_empty() { 0 } else {
+        let msb = digits[digits.len() - 1];
+        let msb_bits = 64 - msb.leading_zeros();
+        ((digits.len() - 1) as u32) * from_shift + msb_bits
+    };
+
+    let output_len = if total_bits == 0 { 1 } else { total_bits.div_ceil(to_shift) };
+    result.reserve(output_len as usize);
+
+    // Whole blocks of lcm(from_shift, to_shift) bits map to whole output
+    // digits independently of each other, so they can be packed in bulk
+    let block_bits = from_shift * to_shift / gcd(from_shift, to_shift);
+    let mut digits = digits;
+    if block_bits <= 64 {
+        let in_per_block = (block_bits / from_shift) as usize;
+        let full = digits.len() / in_per_block * in_per_block;
+        pack_blocks(&digits[..full], from_shift, to_shift, block_bits, result);
+        digits = &digits[full..];
+    }
+
+    // The buffer holds fewer than to_shift bits before each digit goes in,
+    // so it never needs more than to_shift - 1 + from_shift <= 63 bits
+    // while both bases are at most MAX_BASE = 2^32
+    let mut buffer = 0u64;
+    let mut buffer_bits = 0u32;
+
+    for &digit in digits {
+        debug_assert!(buffer_bits + from_shift <= 64);
+        buffer |= digit << buffer_bits;
+        buffer_bits += from_shift;
+
+        while buffer_bits >= to_shift {
+            result.push(buffer & ((1u64 << to_shift) - 1));
+            buffer >>= to_shift;
+            buffer_bits -= to_shift;
+        }
+    }
+
+    // The drain loop above leaves fewer than to_shift bits, so the residual
+    // is a single valid output digit even when from_shift > to_shift
+    if buffer_bits > 0 {
+        result.push(buffer);
+    }
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Repack whole blocks of `block_bits` bits, using AVX2 when the CPU has it
+fn pack_blocks(digits: &[u64], from_shift: u32, to_shift: u32, block_bits: u32, result: &mut Vec<u64>) {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was just checked
+        unsafe { avx2::pack_blocks(digits, from_shift, to_shift, block_bits, result) };
+        return;
+    }
+
+    pack_blocks_scalar(digits, from_shift, to_shift, block_bits, result);
+}
+
+fn pack_blocks_scalar(digits: &[u64], from_shift: u32, to_shift: u32, block_bits: u32, result: &mut Vec<u64>) {
+    let out_per_block = block_bits / to_shift;
+    let mask = (1u64 << to_shift) - 1;
+
+    for block in digits.chunks_exact((block_bits / from_shift) as usize) {
+        let mut word = 0u64;
+        for (i, &digit) in block.iter().enumerate() {
+            word |= digit << (i as u32 * from_shift);
+        }
+        for j in 0..out_per_block {
+            result.push((word >> (j * to_shift)) & mask);
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+mod avx2 {
+    use alloc::vec::Vec;
+    use core::arch::x86_64::*;
+
+    /// Four blocks at a time, one per 64-bit lane
+    ///
+    /// Lane `k` gathers the digits of block `k` with a stride of one block,
+    /// so each step of the shift-and-or loop advances all four blocks.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn pack_blocks(
+        digits: &[u64],
+        from_shift: u32,
+        to_shift: u32,
+        block_bits: u32,
+        result: &mut Vec<u64>,
+    ) {
+        let in_per_block = (block_bits / from_shift) as usize;
+        let out_per_block = (block_bits / to_shift) as usize;
+        let group = 4 * in_per_block;
+        let full = digits.len() / group * group;
+
+        let stride = in_per_block as i64;
+        let lanes = _mm256_set_epi64x(3 * stride, 2 * stride, stride, 0);
+        let mask = _mm256_set1_epi64x(((1u64 << to_shift) - 1) as i64);
+        let mut out = [0u64; 4];
+
+        for start in (0..full).step_by(group) {
+            let mut word = _mm256_setzero_si256();
+            for i in 0..in_per_block {
+                // Every lane reads digits[start + k * in_per_block + i] < digits[full]
+                let digit = _mm256_i64gather_epi64::<8>(digits.as_ptr().add(start + i) as *const i64, lanes);
+                let shift = _mm_cvtsi64_si128((i as u32 * from_shift) as i64);
+                word = _mm256_or_si256(word, _mm256_sll_epi64(digit, shift));
+            }
+
+            let base = result.len();
+            result.resize(base + 4 * out_per_block, 0);
+            for j in 0..out_per_block {
+                let shift = _mm_cvtsi64_si128((j * to_shift as usize) as i64);
+                let packed = _mm256_and_si256(_mm256_srl_epi64(word, shift), mask);
+                _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, packed);
+                for (k, &value) in out.iter().enumerate() {
+                    result[base + k * out_per_block + j] = value;
+                }
+            }
+        }
+
+        super::pack_blocks_scalar(&digits[full..], from_shift, to_shift, block_bits, result);
+    }
+}
+
+/// How many limbs of `base` are sure to fit in `bits` bits
+///
+/// Each limb is below `2^ceil(log2(base))`. The per-step overflow checks
+/// in the callers still catch anything this lets through.
+fn max_limbs_in_bits(base: u64, bits: usize) -> usize {
+    let limb_bits = (64 - (base - 1).leading_zeros()) as usize;
+    bits / limb_bits
+}
+
+/// Whether the number's value fits in a u128
+///
+/// This is exactly when `convert_base` can take its u128 fast path.
+/// Inputs short enough to fit whatever their digits are answer straight
+/// away, and inputs whose top digit alone needs more than 128 bits are
+/// rejected from the length; only the band in between accumulates the
+/// value with overflow checks. Zero limbs at the top don't count.
+///
+/// ```
+/// use fast_base_convert::fits_in_u128;
+///
+/// assert!(fits_in_u128(&[1; 128], 2));
+/// assert!(!fits_in_u128(&[1; 129], 2));
+/// ```
+pub fn fits_in_u128(digits: &[u64], base: u64) -> bool {
+    if !(2..=MAX_BASE).contains(&base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+
+    significant_len(digits) <= max_limbs_in_bits(base, 128) || try_convert_to_u128(digits, base).is_some()
+}
+
+fn try_convert_to_u128(digits: &[u64], base: u64) -> Option<u128> {
+    // Quick size check: the top digit is worth at least 2^(floor(log2(base)) * (len - 1))
+    let digits = &digits[..significant_len(digits)];
+    if digits.len() > 1 && base.ilog2() as usize * (digits.len() - 1) >= 128 {
+        return None;
+    }
+
+    let mut result = 0u128;
+    let base_u128 = base as u128;
+
+    // Process from most significant to least significant to avoid overflow
+    for &digit in digits.iter().rev() {
+        let digit_u128 = digit as u128;
+
+        // Check for overflow before multiplication
+        if result > u128::MAX / base_u128 {
+            return None;
+        }
+        result *= base_u128;
+
+        // Check for overflow before addition
+        if result > u128::MAX - digit_u128 {
+            return None;
+        }
+        result += digit_u128;
+    }
+
+    Some(result)
+}
+
+fn convert_from_u128(mut num: u128, base: u64, result: &mut Vec<u64>) {
+    if num == 0 {
+        result.push(0);
+        return;
+    }
+
+    let base_u128 = base as u128;
+
+    while num > 0 {
+        result.push((num % base_u128) as u64);
+        num /= base_u128;
+    }
+}
+
+/// A 256-bit integer as eight 32-bit limbs, least significant first
+///
+/// Bases are at most 2^32, so a limb times a base plus a carry, and a
+/// remainder shifted above a limb, both fit in a u64 and every step uses
+/// plain hardware multiplication and division.
+type U256 = [u64; 8];
+
+fn try_convert_to_u256(digits: &[u64], base: u64) -> Option<U256> {
+    // Even base 2 can't hold more than 256 significant digits; bail before
+    // walking a long input only to overflow at the end. This is looser
+    // than max_limbs_in_bits so that base 10 can use all ~77 digits.
+    if digits.len() > 256 {
+        return None;
+    }
+
+    let mut num: U256 = [0; 8];
+    for &digit in digits.iter().rev() {
+        let mut carry = digit;
+        for limb in num.iter_mut() {
+            let value = *limb * base + carry;
+            *limb = value & 0xFFFF_FFFF;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+
+    Some(num)
+}
+
+fn convert_from_u256(mut num: U256, base: u64, result: &mut Vec<u64>) {
+    let mut len = num.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+    if len == 0 {
+        result.push(0);
+        return;
+    }
+
+    while len > 0 {
+        let mut remainder = 0u64;
+        for limb in num[..len].iter_mut().rev() {
+            let value = (remainder << 32) | *limb;
+            *limb = value / base;
+            remainder = value % base;
+        }
+        result.push(remainder);
+
+        while len > 0 && num[len - 1] == 0 {
+            len -= 1;
+        }
+    }
+}
+
+/// The smallest `(a, b)` with `from_base^a == to_base^b`, if any
+///
+/// When this is `Some`, every group of `a` input digits converts to
+/// exactly `b` output digits on its own, which is how the aligned fast
+/// path works. Alignments whose groups would overflow u128 are reported as
+/// `None`, as are bases below 2.
+///
+/// This is the only relationship between bases that makes output digits
+/// depend on a fixed group of input digits. A base that merely divides the
+/// other, such as 10 and 30, does not: every base-30 digit of a decimal
+/// number depends on all of its decimal digits, so those pairs take the
+/// general path.
+///
+/// ```
+/// use fast_base_convert::aligned_exponents;
+///
+/// assert_eq!(aligned_exponents(4, 16), Some((2, 1)));
+/// assert_eq!(aligned_exponents(10, 7), None);
+/// ```
+pub fn aligned_exponents(from_base: u64, to_base: u64) -> Option<(usize, usize)> {
+    // Quick check for common aligned bases
+    match (from_base, to_base) {
+        // Base 4 and 16: 4^2 = 16
+        (4, 16) => return Some((2, 1)),
+        (16, 4) => return Some((1, 2)),
+        // Base 8 and 64: 8^2 = 64
+        (8, 64) => return Some((2, 1)),
+        (64, 8) => return Some((1, 2)),
+        // Base 9 and 27: 9^3 = 27^2
+        (9, 27) => return Some((3, 2)),
+        (27, 9) => return Some((2, 3)),
+        // Base 27 and 3: 27 = 3^3
+        (27, 3) => return Some((1, 3)),
+        (3, 27) => return Some((3, 1)),
+        // Base 16 and 2: 16 = 2^4
+        (16, 2) => return Some((1, 4)),
+        (2, 16) => return Some((4, 1)),
+        // Base 32 and 2: 32 = 2^5
+        (32, 2) => return Some((1, 5)),
+        (2, 32) => return Some((5, 1)),
+        _ => {}
+    }
+
+    // A base and one of its own powers, such as 10 and 1000, group digits
+    // directly without factoring either base
+    if let Some(k) = power_of_self_exponent(from_base, to_base) {
+        return Some((k, 1));
+    }
+    if let Some(k) = power_of_self_exponent(to_base, from_base) {
+        return Some((1, k));
+    }
+
+    // General case: the bases are aligned exactly when both are powers of
+    // a common root. Writing each as its smallest root (9 = 3^2, 27 = 3^3)
+    // makes that a comparison, and the exponents give 9^3 = 27^2.
+    if from_base < 2 || to_base < 2 {
+        return None;
+    }
+    let (from_root, from_exp) = is_perfect_power(from_base).unwrap_or((from_base, 1));
+    let (to_root, to_exp) = is_perfect_power(to_base).unwrap_or((to_base, 1));
+    if from_root != to_root {
+        return None;
+    }
+
+    let g = gcd(from_exp, to_exp);
+    let (exp_a, exp_b) = ((to_exp / g) as usize, (from_exp / g) as usize);
+
+    // A chunk holds up to from_base^exp_a - 1, accumulated in u128 by
+    // convert_aligned_bases, so reject alignments whose chunks can't fit
+    (from_base as u128).checked_pow(exp_a as u32).is_some().then_some((exp_a, exp_b))
+}
+
+/// The `k >= 2` with `base^k == target`, if there is one
+fn power_of_self_exponent(base: u64, target: u64) -> Option<usize> {
+    if base < 2 {
+        return None;
+    }
+
+    let mut power = base;
+    let mut k = 1;
+    while power < target {
+        power = power.checked_mul(base)?;
+        k += 1;
+    }
+
+    (power == target && k >= 2).then_some(k)
+}
+
+/// Factor `n` into sorted `(prime, exponent)` pairs
+///
+/// Plain trial division by 2 and then odd numbers, so the cost is
+/// O(sqrt(n)) in the worst case: instant for any base, but up to ~2^31
+/// steps for a 64-bit prime. 0 and 1 have no prime factors and
+/// give an empty vector.
+///
+/// ```
+/// use fast_base_convert::prime_factorization;
+///
+/// assert_eq!(prime_factorization(360), vec![(2, 3), (3, 2), (5, 1)]);
+/// ```
+pub fn prime_factorization(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n <= 1 {
+        return factors;
+    }
+
+    if n.is_multiple_of(2) {
+        let count = n.trailing_zeros();
+        factors.push((2, count));
+        n >>= count;
+    }
+
+    let mut p = 3u64;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            let mut count = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                count += 1;
+            }
+            factors.push((p, count));
+        }
+        p += 2;
+    }
+
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors.sort();
+    factors
+}
+
+/// `from_base^i` for each position in an aligned chunk of `exp_a` digits
+fn aligned_from_powers(from_base: u64, exp_a: usize) -> Vec<u128> {
+    let mut from_powers = Vec::with_capacity(exp_a);
+    from_powers.push(1u128);
+    for i in 1..exp_a {
+        from_powers.push(from_powers[i-1] * from_base as u128);
+    }
+    from_powers
+}
+
+/// Convert `exp_a` input digits at a time into `exp_b` output digits
+///
+/// Chunks are taken from the least significant end, and each one emits its
+/// `exp_b` digits least significant first, so concatenating the chunks
+/// keeps the overall order. A partial final chunk is the top of the number
+/// and simply has zero high digits; it still emits `exp_b` digits, and the
+/// caller trims any leading zeros this leaves.
+fn convert_aligned_bases(
+    digits: &[u64],
+    to_base: u64,
+    from_powers: &[u128],
+    exp_b: usize,
+    result: &mut Vec<u64>,
+) {
+    let exp_a = from_powers.len();
+    let output_len = digits.len().div_ceil(exp_a) * exp_b;
+    result.reserve(output_len);
+
+    for chunk in digits.chunks(exp_a) {
+        let mut value = 0u128;
+        for (i, &digit) in chunk.iter().enumerate() {
+            value += digit as u128 * from_powers[i];
+        }
+
+        for _ in 0..exp_b {
+            result.push((value % to_base as u128) as u64);
+            value /= to_base as u128;
+        }
+    }
+}
+
+// The division loops compute `carry * from_base + digit` in u64. With
+// `carry < to_base` and `digit < from_base` that is at most
+// `from_base * to_base - 1`, which fits for every pair of allowed bases.
+const _: () = assert!((MAX_BASE as u128) * (MAX_BASE as u128) - 1 <= u64::MAX as u128);
+
+/// One step of the division loops
+///
+/// The `checked` feature turns a broken invariant into a panic naming the
+/// operands, where a release build would otherwise wrap silently.
+#[inline(always)]
+fn carry_step(carry: u64, from_base: u64, digit: u64) -> u64 {
+    #[cfg(feature = "checked")]
+    {
+        carry
+            .checked_mul(from_base)
+            .and_then(|value| value.checked_add(digit))
+            .unwrap_or_else(|| panic!("Carry step overflowed: {} * {} + {}", carry, from_base, digit))
+    }
+
+    #[cfg(not(feature = "checked"))]
+    {
+        carry * from_base + digit
+    }
+}
+
+/// Division by a fixed divisor with a multiply and one correction
+///
+/// `multiplier` is `floor((2^64 - 1) / divisor)`, so the high half of
+/// `n * multiplier` is the quotient or one below it. A single compare and
+/// subtract fixes that up, which is cheaper than a 64-bit
+/// hardware division on most CPUs.
+#[derive(Debug, Clone, Copy)]
+struct Reciprocal {
+    divisor: u64,
+    multiplier: u64,
+}
+
+impl Reciprocal {
+    fn new(divisor: u64) -> Self {
+        debug_assert!(divisor >= 2);
+        Reciprocal { divisor, multiplier: u64::MAX / divisor }
+    }
+
+    #[inline(always)]
+    fn div_rem(self, n: u64) -> (u64, u64) {
+        let mut quotient = ((n as u128 * self.multiplier as u128) >> 64) as u64;
+        let mut remainder = n - quotient * self.divisor;
+        if remainder >= self.divisor {
+            quotient += 1;
+            remainder -= self.divisor;
+        }
+        (quotient, remainder)
+    }
+}
+
+// Optimized general case with various tricks for better performance
+fn convert_general_optimized_tricks(
+    digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    options: &ConvertOptions,
+    current: &mut Vec<u64>,
+    next_current: &mut Vec<u64>,
+    result: &mut Vec<u64>,
+) -> Strategy {
+    // Trick 1: Fast path for single digit
+    if digits.len() == 1 {
+        let digit = digits[0];
+        if digit < to_base {
+            result.push(digit);
+            return Strategy::General;
+        }
+        let mut value = digit;
+        while value > 0 {
+            result.push(value % to_base);
+            value /= to_base;
+        }
+        return Strategy::General;
+    }
+
+    // Trick 2: Past a few dozen digits, divide-and-conquer with Karatsuba
+    // multiplication beats repeated division (about 2x at 100 digits, 10x+
+    // at 1000 on x86_64), so switch to the sub-quadratic algorithm
+    if digits.len() >= options.divide_and_conquer_threshold {
+        divide_conquer::convert_into(digits, from_base, to_base, result);
+        return Strategy::DivideAndConquer;
+    }
+
+    // Trick 3: Only reachable with a raised divide-and-conquer threshold.
+    // Long inputs shrinking to a smaller base walk the digits in chunks
+    if digits.len() > options.chunked_threshold && to_base < from_base {
+        convert_repeated_division_chunked(digits, from_base, to_base, options.chunk_size.max(1), result);
+        return Strategy::GeneralChunked;
+    }
+
+    // Trick 4: Estimate output size more accurately
+    result.reserve(estimate_output_len(digits.len(), from_base, to_base));
+
+    // Trick 5: Use working vectors supplied by the caller so they can be reused
+    current.clear();
+    current.extend_from_slice(digits);
+    next_current.clear();
+    next_current.reserve(current.len() + 1);
+
+    // Trick 6: Cache frequently accessed values, and divide by to_base
+    // with a precomputed reciprocal instead of a hardware division
+    let from_base_cached = from_base;
+    let to_base_reciprocal = Reciprocal::new(to_base);
+
+    while !is_zero(current) {
+        let mut carry = 0u64;
+        next_current.clear();
+
+        let current_len = current.len();
+
+        // Trick 7: Optimized loop with manual unrolling for better performance
+        if current_len >= 16 {
+            // Process 16 elements at a time for maximum efficiency
+            let chunks = current_len / 16;
+            let remainder = current_len % 16;
+            let mut i = current_len;
+
+            for _ in 0..chunks {
+                i -= 16;
+                // Manually unroll 16 iterations, most significant digit first
+                macro_rules! process_digit {
+                    ($idx:expr) => {
+                        let value = carry_step(carry, from_base_cached, current[i + $idx]);
+                        let (quotient, remainder) = to_base_reciprocal.div_rem(value);
+                        carry = remainder;
+                        if !next_current.is_empty() || quotient != 0 {
+                            next_current.push(quotient);
+                        }
+                    };
+                }
+
+                process_digit!(15);
+                process_digit!(14);
+                process_digit!(13);
+                process_digit!(12);
+                process_digit!(11);
+                process_digit!(10);
+                process_digit!(9);
+                process_digit!(8);
+                process_digit!(7);
+                process_digit!(6);
+                process_digit!(5);
+                process_digit!(4);
+                process_digit!(3);
+                process_digit!(2);
+                process_digit!(1);
+                process_digit!(0);
+            }
+
+            // Process remaining elements
+            for _j in 0..remainder {
+                i -= 1;
+                let value = carry_step(carry, from_base_cached, current[i]);
+                let (quotient, remainder) = to_base_reciprocal.div_rem(value);
+                carry = remainder;
+                if !next_current.is_empty() || quotient != 0 { next_current.push(quotient); }
+            }
+        } else if current_len >= 4 {
+            // 4-way unrolling for medium arrays
+            let chunks = current_len / 4;
+            let remainder = current_len % 4;
+            let mut i = current_len;
+
+            for _ in 0..chunks {
+                i -= 4;
+                let v1 = carry_step(carry, from_base_cached, current[i+3]);
+                let (q1, remainder) = to_base_reciprocal.div_rem(v1);
+                carry = remainder;
+                if !next_current.is_empty() || q1 != 0 { next_current.push(q1); }
+
+                let v2 = carry_step(carry, from_base_cached, current[i+2]);
+                let (q2, remainder) = to_base_reciprocal.div_rem(v2);
+                carry = remainder;
+                if !next_current.is_empty() || q2 != 0 { next_current.push(q2); }
+
+                let v3 = carry_step(carry, from_base_cached, current[i+1]);
+                let (q3, remainder) = to_base_reciprocal.div_rem(v3);
+                carry = remainder;
+                if !next_current.is_empty() || q3 != 0 { next_current.push(q3); }
+
+                let v4 = carry_step(carry, from_base_cached, current[i]);
+                let (q4, remainder) = to_base_reciprocal.div_rem(v4);
+                carry = remainder;
+                if !next_current.is_empty() || q4 != 0 { next_current.push(q4); }
+            }
+
+            for _j in 0..remainder {
+                i -= 1;
+                let value = carry_step(carry, from_base_cached, current[i]);
+                let (quotient, remainder) = to_base_reciprocal.div_rem(value);
+                carry = remainder;
+                if !next_current.is_empty() || quotient != 0 { next_current.push(quotient); }
+            }
+        } else {
+            // Standard processing for very small arrays
+            for i in (0..current_len).rev() {
+                let value = carry_step(carry, from_base_cached, current[i]);
+                let (quotient, remainder) = to_base_reciprocal.div_rem(value);
+                carry = remainder;
+                if !next_current.is_empty() || quotient != 0 { next_current.push(quotient); }
+            }
+        }
+
+        // Trick 8: Reverse in-place and swap vectors
+        next_current.reverse();
+        result.push(carry);
+        core::mem::swap(current, next_current);
+    }
+
+    // Trick 9: Remove leading zeros efficiently
+    while result.len() > 1 && result.last() == Some(&0) {
+        result.pop();
+    }
+
+    Strategy::General
+}
+
+/// Repeated division, walking each pass `chunk_size` digits at a time
+///
+/// This is the same O(n^2) algorithm as the general loop; chunking only
+/// changes the memory access pattern, not the number of steps. The
+/// `chunked_scaling` benchmark shows it growing quadratically alongside
+/// plain repeated division, while divide-and-conquer is sub-quadratic.
+fn convert_repeated_division_chunked(digits: &[u64], from_base: u64, to_base: u64, chunk_size: usize, result: &mut Vec<u64>) {
+    let mut current_digits = digits.to_vec();
+
+    while !is_zero(&current_digits) {
+        let mut carry = 0u64;
+        let mut next_digits = Vec::with_capacity(current_digits.len());
+
+        // Most significant chunk first, as long division requires
+        for chunk in current_digits.chunks(chunk_size).rev() {
+            for &digit in chunk.iter().rev() {
+                let value = carry_step(carry, from_base, digit);
+                let quotient = value / to_base;
+                carry = value % to_base;
+
+                if !next_digits.is_empty() || quotient != 0 {
+                    next_digits.push(quotient);
+                }
+            }
+        }
+
+        next_digits.reverse();
+        result.push(carry);
+        current_digits = next_digits;
+    }
+
+    // Remove leading zeros
+    while result.len() > 1 && result.last() == Some(&0) {
+        result.pop();
+    }
+}
+
+/// Page size, CPU count and whether the machine has more than one NUMA node
+///
+/// The page size comes from `sysconf(_SC_PAGESIZE)` and the CPU count from
+/// `std::thread::available_parallelism`, falling back to 1. NUMA is
+/// reported when `/sys/devices/system/node/` lists more than one `nodeN`
+/// entry; a missing directory means no NUMA.
+#[cfg(all(target_os = "linux", feature = "std"))]
+pub fn get_linux_system_info() -> (usize, usize, bool) {
+    // SAFETY: sysconf has no preconditions
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    let page_size = if page_size > 0 { page_size as usize } else { 4096 };
+
+    let cpu_count = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+    let numa_nodes = std::fs::read_dir("/sys/devices/system/node/")
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| {
+                    let name = entry.file_name();
+                    let name = name.to_string_lossy();
+                    name.strip_prefix("node").is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+                })
+                .count()
+        })
+        .unwrap_or(0);
+
+    (page_size, cpu_count, numa_nodes > 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_of_two_conversion() {
+        // 16 (2^4) to 8 (2^3)
+        let input = vec![0xA, 0xB, 0xC]; // 0xCBA = 3258
+        let result = convert_base(&input, 16, 8);
+        let baseline = crate::baseline::convert_base(&input, 16, 8);
+        // Verify match with baseline
+        assert_eq!(result, baseline);
+        // 3258 = 2*8^0 + 7*8^1 + 2*8^2 + 6*8^3 = [2, 7, 2, 6]
+        assert_eq!(result, vec![2, 7, 2, 6]);
+    }
+
+    #[test]
+    fn test_power_of_two_residual_is_a_valid_digit() {
+        // Large top digits leave residual bits after the last whole block
+        let pairs = [(16, 4), (16, 8), (256, 32), (1 << 32, 8), (1 << 32, 2)];
+        for (from_base, to_base) in pairs {
+            for len in 1..=7 {
+                let input: Vec<u64> = (0..len).map(|i| from_base - 1 - i % 3).collect();
+                let result = convert_base(&input, from_base, to_base);
+                assert!(result.iter().all(|&d| d < to_base), "{} -> {}: {:?}", from_base, to_base, result);
+                assert_eq!(result, crate::baseline::convert_base(&input, from_base, to_base));
+            }
+        }
+        assert_eq!(convert_base(&[0xF], 16, 4), vec![3, 3]);
+    }
+
+    #[test]
+    fn test_power_of_two_buffer_keeps_every_bit() {
+        // 2^20 -> 2 unpacks 20 bits per limb across many limbs
+        let input: Vec<u64> = (0..50).map(|i| ((1 << 20) - 1) ^ (i * 7919)).collect();
+        let result = convert_base(&input, 1 << 20, 2);
+        assert_eq!(result.len(), 50 * 20);
+        assert_eq!(result, crate::baseline::convert_base(&input, 1 << 20, 2));
+        assert_eq!(convert_base(&result, 2, 1 << 20), input);
+
+        // lcm(31, 32) is too wide for block packing, so the bit buffer sees
+        // up to 31 leftover bits plus a whole 32-bit digit
+        for (from_base, to_base) in [(1u64 << 31, 1u64 << 32), (1 << 32, 1 << 31)] {
+            let input: Vec<u64> = (0..40).map(|i| from_base - 1 - i).collect();
+            assert_eq!(convert_base(&input, from_base, to_base), crate::baseline::convert_base(&input, from_base, to_base));
+        }
+    }
+
+    #[test]
+    fn test_pack_blocks_simd_matches_scalar() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0xa5);
+        for len in [0, 3, 11, 12, 13, 100, 1001] {
+            // hex -> octal: 12-bit blocks of three hex digits
+            let input: Vec<u64> = (0..len).map(|_| rng.gen_range(0..16)).collect();
+            let full = len / 3 * 3;
+            let mut scalar = Vec::new();
+            pack_blocks_scalar(&input[..full], 4, 3, 12, &mut scalar);
+
+            #[cfg(all(target_arch = "x86_64", feature = "std"))]
+            if is_x86_feature_detected!("avx2") {
+                let mut simd = Vec::new();
+                unsafe { avx2::pack_blocks(&input[..full], 4, 3, 12, &mut simd) };
+                assert_eq!(simd, scalar, "len {}", len);
+            }
+
+            assert_eq!(convert_base(&input, 16, 8), crate::baseline::convert_base(&input, 16, 8));
+            let bits: Vec<u64> = input.iter().map(|&d| d & 1).collect();
+            assert_eq!(convert_base(&bits, 2, 16), crate::baseline::convert_base(&bits, 2, 16));
+        }
+    }
+
+    #[cfg(all(target_os = "linux", feature = "std"))]
+    #[test]
+    fn test_get_linux_system_info() {
+        let (page_size, cpu_count, _numa) = get_linux_system_info();
+        assert!(page_size.is_power_of_two() && page_size >= 4096);
+        assert!(cpu_count >= 1);
+    }
+
+    #[test]
+    fn test_small_number_optimization() {
+        // 12345 from base 10 to base 16
         let input = vec![5, 4, 3, 2, 1];
         let result = convert_base(&input, 10, 16);
         let baseline = crate::baseline::convert_base(&input, 10, 16);
@@ -293,207 +1779,775 @@ mod tests {
     }
 
     #[test]
-    fn test_general_case() {
-        // Convert between non-aligned bases
-        let input = vec![9, 8, 7];
-        let result = convert_base(&input, 10, 7);
-        // Verify with baseline
-        let expected = crate::baseline::convert_base(&input, 10, 7);
-        assert_eq!(result, expected);
+    fn test_try_convert_base() {
+        assert_eq!(try_convert_base(&[5, 5, 2], 10, 16), Ok(vec![15, 15]));
+        assert_eq!(try_convert_base(&[1], 1, 16), Err(ConvertError::BaseOutOfRange { base: 1 }));
+        assert_eq!(try_convert_base(&[1], 10, MAX_BASE + 1), Err(ConvertError::BaseOutOfRange { base: MAX_BASE + 1 }));
+        assert_eq!(
+            try_convert_base(&[3, 10], 10, 16),
+            Err(ConvertError::InvalidDigit { digit: 10, base: 10, index: 1 })
+        );
     }
-}
 
-// Optimized general case with various tricks for better performance
-fn convert_general_optimized_tricks(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
-    // Trick 1: Fast path for single digit
-    if digits.len() == 1 {
-        let digit = digits[0];
-        if digit < to_base {
-            return vec![digit];
+    #[test]
+    fn test_equal_bases_reject_invalid_digits() {
+        assert!(std::panic::catch_unwind(|| convert_base(&[999], 10, 10)).is_err());
+        assert!(std::panic::catch_unwind(|| convert_base(&[3, 16], 16, 16)).is_err());
+        assert_eq!(convert_base(&[9, 9], 10, 10), vec![9, 9]);
+    }
+
+    #[test]
+    fn test_convert_base_padded() {
+        let padded = convert_base_padded(&[5, 5, 2], 10, 16, 4);
+        assert_eq!(padded, vec![15, 15, 0, 0]);
+        assert_eq!(crate::utils::digits_to_string(&padded, 16), "00ff");
+
+        assert_eq!(convert_base_padded(&[0], 10, 2, 8), vec![0; 8]);
+        // Already long enough
+        assert_eq!(convert_base_padded(&[5, 5, 2], 10, 2, 4), convert_base(&[5, 5, 2], 10, 2));
+        assert_eq!(convert_base_padded(&[5, 5, 2], 10, 16, 0), vec![15, 15]);
+    }
+
+    #[test]
+    fn test_repack_bits() {
+        // Four 12-bit values are six bytes, the top zero byte trimmed
+        let bytes = repack_bits(&[0xABC, 0x123, 0xFFF, 0x001], 12, 8);
+        assert_eq!(bytes, vec![0xBC, 0x3A, 0x12, 0xFF, 0x1F]);
+        assert_eq!(repack_bits(&bytes, 8, 12), vec![0xABC, 0x123, 0xFFF, 0x001]);
+        assert_eq!(repack_bits(&[0, 0], 12, 8), vec![0]);
+
+        // Wide values agree with going through 1-bit values
+        let values: Vec<u64> = (0..40u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 1).collect();
+        for (from_bits, to_bits) in [(63, 8), (63, 64), (63, 40), (63, 33)] {
+            let repacked = repack_bits(&values, from_bits, to_bits);
+            assert_eq!(repack_bits(&repacked, to_bits, 1), repack_bits(&values, from_bits, 1), "{} -> {}", from_bits, to_bits);
+            assert_eq!(repack_bits(&repacked, to_bits, from_bits), values);
         }
-        let mut result = Vec::with_capacity(2);
-        let mut value = digit;
-        while value > 0 {
-            result.push(value % to_base);
-            value /= to_base;
+        assert_eq!(repack_bits(&[u64::MAX], 64, 64), vec![u64::MAX]);
+        assert_eq!(repack_bits(&[u64::MAX], 64, 32), vec![u32::MAX as u64; 2]);
+
+        assert!(std::panic::catch_unwind(|| repack_bits(&[1], 0, 8)).is_err());
+        assert!(std::panic::catch_unwind(|| repack_bits(&[1], 8, 65)).is_err());
+        assert!(std::panic::catch_unwind(|| repack_bits(&[1 << 40], 40, 48)).is_err());
+    }
+
+    #[test]
+    fn test_convert_base_exact() {
+        // 255 needs 3 octal digits and pads to 5
+        assert_eq!(convert_base_exact(&[5, 5, 2], 10, 8, 5), Ok(vec![7, 7, 3, 0, 0]));
+        assert_eq!(convert_base_exact(&[5, 5, 2], 10, 8, 3), Ok(vec![7, 7, 3]));
+        // 8^5 = 32768 needs 6 octal digits
+        assert_eq!(
+            convert_base_exact(&[8, 6, 7, 2, 3], 10, 8, 5),
+            Err(ConvertError::OverflowsFixedWidth { len: 6, width: 5 })
+        );
+
+        assert_eq!(convert_base_exact(&[0, 0], 10, 2, 4), Ok(vec![0; 4]));
+        assert_eq!(convert_base_exact(&[], 10, 2, 0), Ok(vec![]));
+        assert_eq!(
+            convert_base_exact(&[10], 10, 2, 8),
+            Err(ConvertError::InvalidDigit { digit: 10, base: 10, index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_conversion_plan_matches_convert_base() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ConversionPlan>();
+
+        let inputs: Vec<Vec<u64>> = vec![vec![0], vec![], vec![5, 3], vec![1; 30], vec![2; 45], vec![3; 200]];
+        for (from_base, to_base) in [(10, 2), (4, 16), (9, 27), (243, 59049), (10, 7), (10, 10), (1000, 7)] {
+            let plan = ConversionPlan::new(from_base, to_base);
+            for input in &inputs {
+                let input: Vec<u64> = input.iter().map(|&d| d % from_base).collect();
+                assert_eq!(plan.convert(&input), convert_base(&input, from_base, to_base));
+            }
         }
-        return result;
+
+        let plan = ConversionPlan::new(10, 16);
+        assert!(std::panic::catch_unwind(|| plan.convert(&[10])).is_err());
     }
 
-    // Trick 2: For very large numbers, use chunked processing to reduce algorithmic complexity
-    if digits.len() > 2000 && to_base < from_base {
-        return convert_large_number_chunked(digits, from_base, to_base);
+    #[test]
+    fn test_convert_base_be() {
+        // 12345 = 0x3039
+        assert_eq!(convert_base_be(&[1, 2, 3, 4, 5], 10, 16), vec![3, 0, 3, 9]);
+        assert_eq!(convert_base_be(&[3, 0, 3, 9], 16, 10), vec![1, 2, 3, 4, 5]);
+        assert_eq!(convert_base_be(&[0, 0, 1, 0], 2, 10), vec![2]);
     }
 
-    // Trick 3: Estimate output size more accurately
-    let estimated_output_size = if digits.len() <= 1000 {
-        (digits.len() as f64 * (from_base as f64).ln() / (to_base as f64).ln()).ceil() as usize + 8
-    } else {
-        digits.len() * 2
-    };
+    #[test]
+    fn test_converter_reuses_buffers() {
+        let mut converter = Converter::new();
+        // Base 1000 so the 30- and 40-digit inputs overflow 256 bits and
+        // reach the general path
+        let inputs = [vec![9, 8, 7], vec![5, 4, 3, 2, 1], vec![1; 40], vec![6; 25]];
+
+        for input in &inputs {
+            assert_eq!(converter.convert(input, 1000, 7), crate::baseline::convert_base(input, 1000, 7));
+        }
 
-    let mut result = Vec::with_capacity(estimated_output_size);
+        // Buffers keep their capacity after the large conversion
+        let capacity = |c: &Converter| c.scratch.current.capacity() + c.scratch.next_current.capacity();
+        let before = capacity(&converter);
+        assert!(before >= 40);
+        converter.convert(&[1; 30], 1000, 7);
+        assert_eq!(capacity(&converter), before);
+    }
 
-    // Trick 4: Use working vector with pre-allocation and reuse
-    let mut current = digits.to_vec();
-    let mut next_current = Vec::with_capacity(current.len() + 1);
+    #[test]
+    fn test_convert_options_do_not_change_results() {
+        let input: Vec<u64> = (0..3000).map(|i| (i * 7919 + 13) % 1000).collect();
+        let expected = crate::baseline::convert_base(&input, 1000, 7);
+        assert_eq!(convert_base_with_options(&input, 1000, 7, &ConvertOptions::default()), expected);
+
+        // Raising the divide-and-conquer threshold exposes the chunked loop
+        let no_dc = ConvertOptions { divide_and_conquer_threshold: usize::MAX, ..ConvertOptions::default() };
+        for chunk_size in [0, 1, 7, 64, 5000] {
+            let options = ConvertOptions { chunk_size, ..no_dc };
+            let mut converter = Converter::with_options(options);
+            let mut out = Vec::new();
+            assert_eq!(converter.convert_validated_into(&input, 1000, 7, &mut out), Strategy::GeneralChunked);
+            assert_eq!(out, expected, "chunk size {}", chunk_size);
+        }
 
-    // Trick 5: Cache frequently accessed values
-    let from_base_cached = from_base;
-    let to_base_cached = to_base;
+        let plain = ConvertOptions { chunked_threshold: usize::MAX, ..no_dc };
+        let mut out = Vec::new();
+        assert_eq!(Converter::with_options(plain).convert_validated_into(&input, 1000, 7, &mut out), Strategy::General);
+        assert_eq!(out, expected);
 
-    while !current.is_empty() && !(current.len() == 1 && current[0] == 0) {
-        let mut carry = 0u64;
-        next_current.clear();
+        let eager_dc = ConvertOptions { divide_and_conquer_threshold: 2, ..ConvertOptions::default() };
+        assert_eq!(convert_base_with_options(&[1; 40], 1000, 7, &eager_dc), crate::baseline::convert_base(&[1; 40], 1000, 7));
+    }
 
-        let current_len = current.len();
+    #[test]
+    fn test_convert_base_into_matches_convert_base() {
+        let cases: [(Vec<u64>, u64, u64); 5] = [
+            (vec![0xA, 0xB, 0xC], 16, 8),
+            (vec![5, 4, 3, 2, 1], 10, 16),
+            (vec![3; 80], 4, 16),
+            (vec![6; 40], 10, 7),
+            (vec![1, 2, 3], 10, 10),
+        ];
+
+        let mut out = vec![99; 3];
+        for (input, from_base, to_base) in &cases {
+            convert_base_into(input, *from_base, *to_base, &mut out);
+            assert_eq!(out, convert_base(input, *from_base, *to_base));
+        }
+    }
 
-        // Trick 6: Optimized loop with manual unrolling for better performance
-        if current_len >= 16 {
-            // Process 16 elements at a time for maximum efficiency
-            let chunks = current_len / 16;
-            let remainder = current_len % 16;
-            let mut i = current_len;
+    #[test]
+    fn test_convert_base_into_keeps_capacity() {
+        let mut out = Vec::with_capacity(256);
+        let ptr = out.as_ptr();
 
-            for _ in 0..chunks {
-                i -= 16;
-                // Manually unroll 16 iterations - aggressive optimization
-                macro_rules! process_digit {
-                    ($idx:expr) => {
-                        let value = carry * from_base_cached + current[i + $idx];
-                        let quotient = value / to_base_cached;
-                        carry = value % to_base_cached;
-                        if !next_current.is_empty() || quotient != 0 {
-                            next_current.push(quotient);
-                        }
-                    };
-                }
+        convert_base_into(&[5, 4, 3, 2, 1], 10, 16, &mut out);
+        convert_base_into(&[6; 40], 10, 7, &mut out);
+        convert_base_into(&[0xF; 30], 16, 2, &mut out);
 
-                process_digit!(0);
-                process_digit!(1);
-                process_digit!(2);
-                process_digit!(3);
-                process_digit!(4);
-                process_digit!(5);
-                process_digit!(6);
-                process_digit!(7);
-                process_digit!(8);
-                process_digit!(9);
-                process_digit!(10);
-                process_digit!(11);
-                process_digit!(12);
-                process_digit!(13);
-                process_digit!(14);
-                process_digit!(15);
+        assert_eq!(out.capacity(), 256);
+        assert_eq!(out.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_concurrent_conversions() {
+        let input: Vec<u64> = (0..60).map(|i| (i * 5 + 1) % 6).collect();
+        let expected = crate::baseline::convert_base(&input, 6, 36);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let input = input.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..200 {
+                        let _ = convert_base(&input, 6, 36);
+                    }
+                    convert_base(&input, 6, 36)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_convert_base_many_matches_convert_base() {
+        let inputs: Vec<Vec<u64>> = (0..50)
+            .map(|n| (0..n * 7).map(|i| (i * 31 + n) % 9).collect())
+            .collect();
+
+        for (from_base, to_base) in [(9, 27), (9, 10), (9, 9)] {
+            let results = convert_base_many(&inputs, from_base, to_base);
+            assert_eq!(results.len(), inputs.len());
+            for (input, result) in inputs.iter().zip(&results) {
+                assert_eq!(result, &convert_base(input, from_base, to_base));
             }
+        }
 
-            // Process remaining elements
-            for _j in 0..remainder {
-                i -= 1;
-                let value = carry * from_base_cached + current[i];
-                let quotient = value / to_base_cached;
-                carry = value % to_base_cached;
-                if !next_current.is_empty() || quotient != 0 { next_current.push(quotient); }
+        assert!(convert_base_many(&[], 2, 16).is_empty());
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn test_checked_carry_step_overflow() {
+        // Bases past MAX_BASE bypass validation only by calling the loop directly
+        let big = 1u64 << 40;
+        let result = std::panic::catch_unwind(|| {
+            let mut result = Vec::new();
+            convert_general_optimized_tricks(&[big - 2; 4], big, big - 1, &ConvertOptions::default(), &mut Vec::new(), &mut Vec::new(), &mut result);
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.starts_with("Carry step overflowed"), "{}", message);
+    }
+
+    #[test]
+    fn test_count_digits_matches_convert_base() {
+        let decimal: Vec<u64> = (0..300).map(|i| (i * 7 + 3) % 10).collect();
+        let pairs = [
+            (10, 2), (10, 16), (10, 1000), (10, 7), (16, 2), (2, 16), (8, 32), (9, 27), (27, 9),
+            (7, 1 << 32), ((1 << 32) - 1, 3), (3, 10), (1 << 32, 10),
+        ];
+        for (from_base, to_base) in pairs {
+            for len in [1, 2, 5, 20, 38, 39, 100, 300] {
+                let input = convert_base(&decimal[..len], 10, from_base);
+                assert_eq!(
+                    count_digits(&input, from_base, to_base),
+                    convert_base(&input, from_base, to_base).len(),
+                    "{} -> {} with {} digits", from_base, to_base, input.len()
+                );
             }
-        } else if current_len >= 4 {
-            // 4-way unrolling for medium arrays
-            let chunks = current_len / 4;
-            let remainder = current_len % 4;
-            let mut i = current_len;
+        }
+    }
 
-            for _ in 0..chunks {
-                i -= 4;
-                let v1 = carry * from_base_cached + current[i];
-                let q1 = v1 / to_base_cached;
-                carry = v1 % to_base_cached;
-                if !next_current.is_empty() || q1 != 0 { next_current.push(q1); }
+    #[test]
+    fn test_count_digits_edges() {
+        assert_eq!(count_digits(&[], 10, 2), 1);
+        assert_eq!(count_digits(&[0, 0], 10, 2), 1);
+        assert_eq!(count_digits(&[1, 0, 0], 10, 10), 3);
+        // Leading zeros and exact powers of the target base
+        assert_eq!(count_digits(&[0, 0, 0, 1, 0, 0], 10, 2), 10);
+        assert_eq!(count_digits(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 1], 3, 9), 5);
+        assert_eq!(count_digits(&[8, 8, 8], 9, 3), 6);
+    }
 
-                let v2 = carry * from_base_cached + current[i+1];
-                let q2 = v2 / to_base_cached;
-                carry = v2 % to_base_cached;
-                if !next_current.is_empty() || q2 != 0 { next_current.push(q2); }
+    #[test]
+    fn test_min_base_for_digits() {
+        // 65535 fits in four hex digits, 65536 does not
+        assert_eq!(min_base_for_digits(&[5, 3, 5, 5, 6], 10, 4), Ok(16));
+        assert_eq!(min_base_for_digits(&[6, 3, 5, 5, 6], 10, 4), Ok(17));
+        assert_eq!(min_base_for_digits(&[5, 3, 5, 5, 6], 10, 16), Ok(2));
+        assert_eq!(min_base_for_digits(&[5, 3, 5, 5, 6, 0, 0], 10, 1), Ok(65536));
+        assert_eq!(min_base_for_digits(&[0, 0], 10, 1), Ok(2));
+
+        // 2^64 - 1 needs two digits of base 2^32 and three of 2^32 - 1
+        let max = u32::MAX as u64;
+        assert_eq!(min_base_for_digits(&[max, max], 1 << 32, 2), Ok(1 << 32));
+        assert_eq!(
+            min_base_for_digits(&[max, max], 1 << 32, 1),
+            Err(ConvertError::OutputTooLarge { needed: 2, max: 1 })
+        );
+        assert_eq!(min_base_for_digits(&[], 10, 0), Err(ConvertError::OutputTooLarge { needed: 1, max: 0 }));
+        assert_eq!(min_base_for_digits(&[5], 1, 4), Err(ConvertError::BaseOutOfRange { base: 1 }));
+
+        // Agrees with a linear scan over small bases
+        let input: Vec<u64> = (0..30).map(|i| (i * 7 + 3) % 10).collect();
+        for max_digits in [8, 13, 20] {
+            let linear = (2..=MAX_BASE).find(|&b| convert_base(&input, 10, b).len() <= max_digits).unwrap();
+            assert_eq!(min_base_for_digits(&input, 10, max_digits), Ok(linear));
+        }
+    }
 
-                let v3 = carry * from_base_cached + current[i+2];
-                let q3 = v3 / to_base_cached;
-                carry = v3 % to_base_cached;
-                if !next_current.is_empty() || q3 != 0 { next_current.push(q3); }
+    #[test]
+    fn test_convert_base_capped() {
+        // 10000 limbs of 16 bits are 160000 binary digits
+        let input = vec![65535u64; 10_000];
+        match convert_base_capped(&input, 65536, 2, 1000) {
+            Err(ConvertError::OutputTooLarge { needed, max }) => {
+                assert!(needed >= 160_000);
+                assert_eq!(max, 1000);
+            }
+            other => panic!("expected OutputTooLarge, got {:?}", other),
+        }
 
-                let v4 = carry * from_base_cached + current[i+3];
-                let q4 = v4 / to_base_cached;
-                carry = v4 % to_base_cached;
-                if !next_current.is_empty() || q4 != 0 { next_current.push(q4); }
+        assert_eq!(convert_base_capped(&[5, 5, 2], 10, 16, 8), Ok(vec![15, 15]));
+        assert_eq!(convert_base_capped(&[0, 0, 0], 10, 2, 1), Ok(vec![0]));
+        assert_eq!(convert_base_capped(&[5, 5, 2, 0], 10, 10, 4), Ok(vec![5, 5, 2, 0]));
+        assert_eq!(
+            convert_base_capped(&[5, 5, 2, 0], 10, 10, 3),
+            Err(ConvertError::OutputTooLarge { needed: 4, max: 3 })
+        );
+        assert_eq!(convert_base_capped(&[10], 10, 2, 100), Err(ConvertError::InvalidDigit { digit: 10, base: 10, index: 0 }));
+        assert_eq!(convert_base_capped(&[1], 10, 1, 100), Err(ConvertError::BaseOutOfRange { base: 1 }));
+    }
+
+    #[test]
+    fn test_convert_base_with_leading() {
+        // Bytes 00 00 01 02 read as base 256, least significant first
+        let bytes = [0x02, 0x01, 0x00, 0x00];
+        let (zeros, digits) = convert_base_with_leading(&bytes, 256, 58);
+        assert_eq!(zeros, 2);
+        // 0x0102 = 258 = 4 * 58 + 26
+        assert_eq!(digits, vec![26, 4]);
+
+        assert_eq!(convert_base_with_leading(&[0x02, 0x01], 256, 58), (0, vec![26, 4]));
+        assert_eq!(convert_base_with_leading(&[0, 0], 256, 58), (2, vec![]));
+        assert_eq!(convert_base_with_leading(&[], 256, 58), (0, vec![]));
+        assert!(std::panic::catch_unwind(|| convert_base_with_leading(&[0], 256, 1)).is_err());
+    }
+
+    #[test]
+    fn test_decimal_super_digits_match_baseline() {
+        let decimal: Vec<u64> = (0..1200).map(|i| (i * 7919 + 13) % 10).collect();
+        for len in [64, 65, 100, 577, 1200] {
+            for other in [2, 3, 7, 16, 1001, 65536, 1 << 32] {
+                let input = &decimal[..len];
+                let expected = crate::baseline::convert_base(input, 10, other);
+                assert_eq!(convert_base(input, 10, other), expected, "10 -> {} with {} digits", other, len);
+
+                let back = crate::baseline::convert_base(&expected, other, 10);
+                assert_eq!(convert_base(&expected, other, 10), back, "{} -> 10 with {} digits", other, expected.len());
             }
+        }
 
-            for _j in 0..remainder {
-                i -= 1;
-                let value = carry * from_base_cached + current[i];
-                let quotient = value / to_base_cached;
-                carry = value % to_base_cached;
-                if !next_current.is_empty() || quotient != 0 { next_current.push(quotient); }
+        // Powers of ten end in zero limbs after packing and in zero groups after unpacking
+        let mut power = vec![0u64; 10_001];
+        power[10_000] = 1;
+        let hex = convert_base(&power, 10, 16);
+        assert_eq!(hex, crate::baseline::convert_base(&power, 10, 16));
+        assert_eq!(convert_base(&hex, 16, 10), power);
+        assert_eq!(ConversionPlan::new(16, 10).convert(&hex), power);
+    }
+
+    #[test]
+    fn test_reciprocal_div_rem() {
+        let mut rng = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = || {
+            rng ^= rng << 13;
+            rng ^= rng >> 7;
+            rng ^= rng << 17;
+            rng
+        };
+
+        for divisor in [2, 3, 7, 10, 1000, 65535, 65536, 1_000_000_007, MAX_BASE - 1, MAX_BASE] {
+            let reciprocal = Reciprocal::new(divisor);
+            let largest = (MAX_BASE as u128 * divisor as u128 - 1).min(u64::MAX as u128) as u64;
+            for n in [0, 1, divisor - 1, divisor, divisor + 1, largest, u64::MAX, u64::MAX - divisor] {
+                assert_eq!(reciprocal.div_rem(n), (n / divisor, n % divisor), "{} / {}", n, divisor);
             }
-        } else {
-            // Standard processing for very small arrays
-            for i in (0..current_len).rev() {
-                let value = carry * from_base_cached + current[i];
-                let quotient = value / to_base_cached;
-                carry = value % to_base_cached;
-                if !next_current.is_empty() || quotient != 0 { next_current.push(quotient); }
+            for _ in 0..10_000 {
+                let n = next();
+                assert_eq!(reciprocal.div_rem(n), (n / divisor, n % divisor), "{} / {}", n, divisor);
             }
         }
+    }
 
-        // Trick 7: Reverse in-place and swap vectors
-        next_current.reverse();
-        result.push(carry);
-        std::mem::swap(&mut current, &mut next_current);
+    #[test]
+    fn test_general_path_matches_baseline() {
+        let mut rng = 0x2545_F491_4F6C_DD1Du64;
+        let mut next = || {
+            rng ^= rng << 13;
+            rng ^= rng >> 7;
+            rng ^= rng << 17;
+            rng
+        };
+
+        for _ in 0..200 {
+            let from_base = 2 + next() % (MAX_BASE - 1);
+            let to_base = 2 + next() % (MAX_BASE - 1);
+            let len = 1 + (next() % 63) as usize;
+            let digits: Vec<u64> = (0..len).map(|_| next() % from_base).collect();
+            assert_eq!(
+                convert_base(&digits, from_base, to_base),
+                crate::baseline::convert_base(&digits, from_base, to_base),
+                "{} -> {} with {} digits", from_base, to_base, len
+            );
+        }
     }
 
-    // Trick 8: Remove leading zeros efficiently
-    while result.len() > 1 && result.last() == Some(&0) {
-        result.pop();
+    #[test]
+    fn test_scratch_buffers_reuse() {
+        let mut scratch = ScratchBuffers::new();
+        let decimal: Vec<u64> = (0..500).map(|i| (i * 7 + 3) % 10).collect();
+
+        // Lengths around the fixed-width and divide-and-conquer cutoffs, and
+        // base pairs that switch between strategies on the same scratch
+        for len in [1, 20, 40, 63, 64, 500] {
+            for (from_base, to_base) in [(10, 7), (7, 10), (10, 16), (1000, 3), (9, 27), (10, 10)] {
+                let input = convert_base(&decimal[..len], 10, from_base);
+                assert_eq!(
+                    convert_base_with_scratch(&input, from_base, to_base, &mut scratch),
+                    convert_base(&input, from_base, to_base)
+                );
+            }
+        }
+        assert!(scratch.current.capacity() > 0);
     }
 
-    result
-}
+    #[test]
+    fn test_convert_base_unchecked() {
+        for (digits, from_base, to_base) in [
+            (vec![5, 5, 2], 10, 16),
+            (vec![1, 0, 1, 1], 2, 16),
+            (vec![0, 0], 10, 2),
+            (vec![5, 5, 2, 0], 10, 10),
+            (vec![8; 300], 9, 10),
+        ] {
+            assert_eq!(convert_base_unchecked(&digits, from_base, to_base), convert_base(&digits, from_base, to_base));
+        }
+    }
 
-// Specialized function for very large numbers using chunked processing
-fn convert_large_number_chunked(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
-    // For very large numbers, use a divide-and-conquer approach
-    // Process the number in chunks to reduce the number of iterations
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "convert_base_unchecked called with a digit not below 10")]
+    fn test_convert_base_unchecked_debug_asserts() {
+        convert_base_unchecked(&[10], 10, 2);
+    }
 
-    const CHUNK_SIZE: usize = 64;
-    let mut result = Vec::new();
+    #[test]
+    fn test_convert_base_fixed() {
+        // Shrinking: 0b1101 is one hex digit
+        let (out, len): ([u64; 4], usize) = convert_base_fixed(&[1, 0, 1, 1], 2, 16);
+        assert_eq!((out, len), ([13, 0, 0, 0], 1));
+
+        // Growing: 255 is four base-4 digits
+        let (out, len): ([u64; 4], usize) = convert_base_fixed(&[255], 256, 4);
+        assert_eq!((out, len), ([3, 3, 3, 3], 4));
+
+        let (out, len): ([u64; 1], usize) = convert_base_fixed(&[0, 0], 10, 16);
+        assert_eq!((out, len), ([0], 1));
+
+        // Too big for u128: the stack division path
+        let input: [u64; 40] = core::array::from_fn(|i| (i as u64 * 37 + 11) % 1000);
+        let (out, len): ([u64; 150], usize) = convert_base_fixed(&input, 1000, 7);
+        assert_eq!(out[..len], convert_base(&input, 1000, 7)[..]);
+        assert!(out[len..].iter().all(|&d| d == 0));
+    }
 
-    // Process digits in chunks from least significant to most
-    let mut processed = 0;
-    let mut current_digits = digits.to_vec();
+    #[test]
+    #[should_panic(expected = "Output needs more than 3 digits")]
+    fn test_convert_base_fixed_capacity() {
+        let _: ([u64; 3], usize) = convert_base_fixed(&[255], 256, 4);
+    }
 
-    while !current_digits.is_empty() && !(current_digits.len() == 1 && current_digits[0] == 0) {
-        let mut carry = 0u64;
-        let mut next_digits = Vec::with_capacity(current_digits.len() / CHUNK_SIZE + 1);
+    #[test]
+    fn test_convert_base_in_place() {
+        let cases: [(u64, u64); 8] = [(2, 16), (8, 4096), (9, 27), (10, 1000), (10, 10), (16, 2), (10, 7), (1000, 10)];
+        for (from_base, to_base) in cases {
+            for len in [0, 1, 2, 5, 64, 301] {
+                let input: Vec<u64> = (0..len).map(|i| (i * 7 + 3) % from_base).collect();
+                let mut digits = input.clone();
+                convert_base_in_place(&mut digits, from_base, to_base);
+                assert_eq!(digits, convert_base(&input, from_base, to_base), "{} -> {} at {} digits", from_base, to_base, len);
+            }
+        }
 
-        // Process in chunks for better cache utilization
-        for chunk in current_digits.chunks(CHUNK_SIZE) {
-            for &digit in chunk.iter().rev() {
-                let value = carry * from_base + digit;
-                let quotient = value / to_base;
-                carry = value % to_base;
+        // Shrinking conversions keep the original allocation
+        for (from_base, to_base) in [(2, 16), (9, 27), (10, 10)] {
+            let mut digits: Vec<u64> = (0..1000).map(|i| (i * 7 + 3) % from_base).collect();
+            digits.push(1);
+            let ptr = digits.as_ptr();
+            convert_base_in_place(&mut digits, from_base, to_base);
+            assert_eq!(digits.as_ptr(), ptr);
+        }
 
-                if !next_digits.is_empty() || quotient != 0 {
-                    next_digits.push(quotient);
+        let mut zero = vec![0, 0, 0];
+        convert_base_in_place(&mut zero, 10, 7);
+        assert_eq!(zero, vec![0]);
+    }
+
+    #[test]
+    fn test_power_of_self_alignment() {
+        assert_eq!(power_of_self_exponent(10, 1000), Some(3));
+        assert_eq!(power_of_self_exponent(10, 10), None);
+        assert_eq!(power_of_self_exponent(10, 999), None);
+        assert_eq!(power_of_self_exponent(65536, 1 << 32), Some(2));
+        assert_eq!(aligned_exponents(10, 1000), Some((3, 1)));
+        assert_eq!(aligned_exponents(1000, 10), Some((1, 3)));
+        assert_eq!(aligned_exponents(6, 216), Some((3, 1)));
+
+        let input: Vec<u64> = (0..1000).map(|i| (i * 7 + 3) % 10).collect();
+        let (result, strategy) = convert_base_explained(&input, 10, 1000);
+        assert_eq!(strategy, Strategy::Aligned { exp_a: 3, exp_b: 1 });
+        assert_eq!(result, crate::baseline::convert_base(&input, 10, 1000));
+        assert_eq!(convert_base(&result, 1000, 10), input);
+    }
+
+    #[test]
+    fn test_composite_bases_align() {
+        // 6 = 2 * 3 and 36 = 2^2 * 3^2 share both primes in proportion
+        assert_eq!(prime_factorization(6), vec![(2, 1), (3, 1)]);
+        assert_eq!(prime_factorization(36), vec![(2, 2), (3, 2)]);
+        assert_eq!(aligned_exponents(6, 36), Some((2, 1)));
+        assert_eq!(aligned_exponents(36, 216), Some((3, 2)));
+        // Sharing a prime is not enough: 6^a and 12^b never meet
+        assert_eq!(aligned_exponents(6, 12), None);
+
+        let input: Vec<u64> = (0..5000).map(|i| (i * 7 + 3) % 6).collect();
+        let (result, strategy) = convert_base_explained(&input, 6, 36);
+        assert_eq!(strategy, Strategy::Aligned { exp_a: 2, exp_b: 1 });
+        assert_eq!(result, crate::divide_conquer::convert_base(&input, 6, 36));
+    }
+
+    #[test]
+    fn test_aligned_partial_final_chunk() {
+        // 5 base-4 digits in chunks of 2 leave a lone top digit
+        let input = [3, 1, 2, 0, 1];
+        let mut result = Vec::new();
+        convert_aligned_bases(&input, 16, &aligned_from_powers(4, 2), 1, &mut result);
+        assert_eq!(result, vec![7, 2, 1]);
+        assert_eq!(result, crate::baseline::convert_base(&input, 4, 16));
+
+        // Every remainder modulo exp_a, with nonzero and zero top digits
+        for (from_base, to_base) in [(4, 16), (10, 1000), (9, 27), (27, 9), (8, 32)] {
+            let (exp_a, exp_b) = aligned_exponents(from_base, to_base).unwrap();
+            let powers = aligned_from_powers(from_base, exp_a);
+            for len in 40..40 + 2 * exp_a {
+                for top in [from_base - 1, 1, 0] {
+                    let mut input: Vec<u64> = (0..len as u64).map(|i| (i * 7 + 2) % from_base).collect();
+                    input[len - 1] = top;
+                    let mut result = Vec::new();
+                    convert_aligned_bases(&input, to_base, &powers, exp_b, &mut result);
+                    assert_eq!(result.len(), len.div_ceil(exp_a) * exp_b);
+                    let expected = crate::baseline::convert_base(&input, from_base, to_base);
+                    assert_eq!(crate::utils::trim_leading_zeros(&result), expected, "{} -> {}, {} digits", from_base, to_base, len);
+                    assert_eq!(convert_base(&input, from_base, to_base), expected);
                 }
             }
         }
+    }
 
-        next_digits.reverse();
-        result.push(carry);
-        current_digits = next_digits;
-        processed += 1;
+    #[test]
+    fn test_divisor_bases_do_not_align() {
+        // 10 -> 100 groups decimal digits in pairs, even on long inputs
+        let input: Vec<u64> = (0..2001).map(|i| (i * 7 + 3) % 10).collect();
+        let (result, strategy) = convert_base_explained(&input, 10, 100);
+        assert_eq!(strategy, Strategy::Aligned { exp_a: 2, exp_b: 1 });
+        let (back, strategy) = convert_base_explained(&result, 100, 10);
+        assert_eq!(strategy, Strategy::Aligned { exp_a: 1, exp_b: 2 });
+        assert_eq!(back, input);
+
+        // A divisor that is not a power of a common root has no grouping
+        assert_eq!(aligned_exponents(10, 30), None);
+        assert_eq!(aligned_exponents(3, 30), None);
+        assert_eq!(aligned_exponents(10, 20), None);
+        let (result, strategy) = convert_base_explained(&input, 10, 30);
+        assert!(!matches!(strategy, Strategy::Aligned { .. }));
+        assert_eq!(result, crate::divide_conquer::convert_base(&input, 10, 30));
     }
 
-    // Remove leading zeros
-    while result.len() > 1 && result.last() == Some(&0) {
-        result.pop();
+    #[test]
+    fn test_convert_base_no_trim() {
+        // 0b00000001 is one hex digit once trimmed, two as computed
+        let input = [1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(convert_base(&input, 2, 16), vec![1]);
+        assert_eq!(convert_base_no_trim(&input, 2, 16), vec![1, 0]);
+
+        // Aligned (9^3 = 27^2): 300 digits, too big for the u256 tier, make
+        // 100 groups of two output digits. The top group is [8, 0, 0], or 8,
+        // which comes out as [8, 0].
+        let mut input = vec![8; 298];
+        input.extend([0, 0]);
+        assert_eq!(convert_base(&input, 9, 27).len(), 199);
+        let raw = convert_base_no_trim(&input, 9, 27);
+        assert_eq!(raw.len(), 200);
+        assert_eq!(raw[198..], [8, 0]);
+
+        assert_eq!(convert_base_no_trim(&[3, 2, 0], 10, 10), vec![3, 2, 0]);
+        assert_eq!(convert_base_no_trim(&[], 10, 16), vec![0]);
+        assert_eq!(convert_base_no_trim(&[5, 5, 2], 10, 16), vec![15, 15]);
     }
 
-    result
-}
\ No newline at end of file
+    #[test]
+    fn test_convert_base_explained() {
+        // Least significant first: 0xCBA = 0o6272
+        assert_eq!(convert_base_explained(&[0xA, 0xB, 0xC], 16, 8), (vec![2, 7, 2, 6], Strategy::PowerOfTwo));
+        assert_eq!(convert_base_explained(&[5, 4, 3, 2, 1], 10, 16), (vec![9, 3, 0, 3], Strategy::U128FastPath));
+        assert_eq!(convert_base_explained(&[0, 0], 10, 16), (vec![0], Strategy::Zero));
+        assert_eq!(convert_base_explained(&[3, 2], 10, 10), (vec![3, 2], Strategy::SameBase));
+
+        let wide = vec![8; 60];
+        assert_eq!(convert_base_explained(&wide, 9, 27).1, Strategy::U256FastPath);
+        let long = vec![8; 300];
+        assert_eq!(convert_base_explained(&long, 9, 27).1, Strategy::Aligned { exp_a: 3, exp_b: 2 });
+        assert_eq!(convert_base_explained(&long, 9, 10).1, Strategy::DivideAndConquer);
+
+        let (digits, _) = convert_base_explained(&long, 9, 10);
+        assert_eq!(digits, convert_base(&long, 9, 10));
+    }
+
+    #[test]
+    fn test_prime_factorization_edge_cases() {
+        assert_eq!(prime_factorization(0), vec![]);
+        assert_eq!(prime_factorization(1), vec![]);
+        assert_eq!(prime_factorization(65521), vec![(65521, 1)]);
+        assert_eq!(prime_factorization(1 << 32), vec![(2, 32)]);
+    }
+
+    #[test]
+    fn test_u128_cutoff_depends_on_base() {
+        assert_eq!(max_limbs_in_bits(2, 128), 128);
+        assert_eq!(max_limbs_in_bits(10, 128), 32);
+        assert_eq!(max_limbs_in_bits(65536, 128), 8);
+
+        // 128 binary digits fit exactly, 129 don't
+        assert_eq!(try_convert_to_u128(&[1; 128], 2), Some(u128::MAX));
+        assert_eq!(try_convert_to_u128(&[1; 129], 2), None);
+        let input: Vec<u64> = (0..100).map(|i| i % 2).collect();
+        assert_eq!(convert_base(&input, 2, 10), crate::baseline::convert_base(&input, 2, 10));
+
+        // 8 limbs of base 65536 fit exactly, 9 don't
+        assert_eq!(try_convert_to_u128(&[65535; 8], 65536), Some(u128::MAX));
+        assert_eq!(try_convert_to_u128(&[65535; 9], 65536), None);
+        assert_eq!(try_convert_to_u128(&[1; 9], 65536), None);
+    }
+
+    #[test]
+    fn test_fits_in_u128_at_the_boundary() {
+        // u128::MAX has 39 decimal digits
+        let max = [5, 5, 4, 1, 1, 2, 8, 6, 7, 1, 3, 4, 7, 0, 6, 4, 7, 3, 3, 6, 4, 3, 6, 4, 8, 3, 9, 0, 2, 9, 6, 6, 3, 2, 8, 2, 0, 4, 3];
+        assert!(fits_in_u128(&max, 10));
+        assert_eq!(try_convert_to_u128(&max, 10), Some(u128::MAX));
+
+        let mut over = max;
+        over[0] = 6;
+        assert!(!fits_in_u128(&over, 10));
+        assert!(!fits_in_u128(&[0; 40].iter().chain(&[1]).copied().collect::<Vec<_>>(), 10));
+
+        // Leading zeros don't push a small value off the fast path
+        let mut padded = vec![9; 5];
+        padded.resize(200, 0);
+        assert!(fits_in_u128(&padded, 10));
+        assert_eq!(convert_base_explained(&padded, 10, 7).1, Strategy::U128FastPath);
+
+        assert!(fits_in_u128(&[65535; 8], 65536));
+        assert!(!fits_in_u128(&[0, 0, 0, 0, 0, 0, 0, 0, 1], 65536));
+        assert!(fits_in_u128(&[], 10));
+    }
+
+    #[test]
+    fn test_u256_tier_matches_baseline() {
+        // 39 to 77 decimal digits: too big for u128, small enough for 256 bits
+        for len in [39, 40, 60, 77] {
+            let input: Vec<u64> = (0..len).map(|i| (i * 7 + 3) % 10).collect();
+            assert!(try_convert_to_u128(&input, 10).is_none());
+            assert!(try_convert_to_u256(&input, 10).is_some());
+            for to_base in [2, 16, 36, 65521] {
+                assert_eq!(convert_base(&input, 10, to_base), crate::baseline::convert_base(&input, 10, to_base));
+            }
+        }
+
+        // 2^256 - 1 fits exactly, 2^256 does not
+        assert!(try_convert_to_u256(&[1; 256], 2).is_some());
+        let mut too_big = vec![0; 257];
+        too_big[256] = 1;
+        assert!(try_convert_to_u256(&too_big, 2).is_none());
+        assert!(try_convert_to_u256(&[9; 78], 10).is_none());
+
+        let mut out = Vec::new();
+        convert_from_u256([0; 8], 10, &mut out);
+        assert_eq!(out, vec![0]);
+    }
+
+    #[test]
+    fn test_aligned_chunks_fit_in_u128() {
+        // 59049^9 = 19683^10 = 3^90, more than u128 holds
+        assert_eq!(aligned_exponents(59049, 19683), None);
+        assert_eq!(aligned_exponents(19683, 59049), None);
+
+        let input: Vec<u64> = (0..45).map(|i| (i * 4099 + 11) % 59049).collect();
+        let expected = crate::baseline::convert_base(&input, 59049, 19683);
+        assert_eq!(convert_base(&input, 59049, 19683), expected);
+        assert_eq!(convert_base(&expected, 19683, 59049), input);
+    }
+
+    #[test]
+    fn test_aligned_large_bases_do_not_wrap() {
+        // 60000^2 = 3_600_000_000
+        assert_eq!(aligned_exponents(60000, 3_600_000_000), Some((2, 1)));
+        assert_eq!(aligned_exponents(3_600_000_000, 60000), Some((1, 2)));
+        assert_eq!(aligned_exponents(60000, 60001), None);
+        assert_eq!(aligned_exponents(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_aligned_perfect_power_roots() {
+        // 8 = 2^3, 32 = 2^5: 8^5 = 32^3
+        assert_eq!(aligned_exponents(8, 32), Some((5, 3)));
+        assert_eq!(aligned_exponents(32, 8), Some((3, 5)));
+        // 16 = 2^4, 64 = 2^6: 16^3 = 64^2
+        assert_eq!(aligned_exponents(16, 64), Some((3, 2)));
+        assert_eq!(aligned_exponents(64, 16), Some((2, 3)));
+        assert_eq!(aligned_exponents(243, 2187), Some((7, 5)));
+
+        // convert_base takes the power-of-two path for these, so check the
+        // aligned path directly against the baseline
+        for (from, to) in [(8, 32), (32, 8), (16, 64), (64, 16)] {
+            let (exp_a, exp_b) = aligned_exponents(from, to).unwrap();
+            let input: Vec<u64> = (0..37).map(|i| (i * 7 + 3) % from).collect();
+            let mut actual = Vec::new();
+            convert_aligned_bases(&input, to, &aligned_from_powers(from, exp_a), exp_b, &mut actual);
+            assert_eq!(crate::utils::trim_leading_zeros(&actual), crate::baseline::convert_base(&input, from, to));
+        }
+    }
+
+    #[test]
+    fn test_aligned_exponents_common_root() {
+        assert_eq!(aligned_exponents(1000, 10), Some((1, 3)));
+        assert_eq!(aligned_exponents(10, 100), Some((2, 1)));
+        assert_eq!(aligned_exponents(10000, 10), Some((1, 4)));
+        assert_eq!(aligned_exponents(9, 27), Some((3, 2)));
+        assert_eq!(aligned_exponents(27, 9), Some((2, 3)));
+        assert_eq!(aligned_exponents(8, 16), Some((4, 3)));
+        assert_eq!(aligned_exponents(216, 36), Some((2, 3)));
+        assert_eq!(aligned_exponents(12, 18), None);
+        assert_eq!(aligned_exponents(10, 7), None);
+        assert_eq!(aligned_exponents(1, 1), None);
+        assert_eq!(aligned_exponents(0, 16), None);
+    }
+
+    #[test]
+    fn test_aligned_conversions_match_baseline() {
+        let pairs = [(9, 27), (27, 9), (3, 27), (27, 3), (10, 1000), (1000, 10), (100, 10), (10, 10000)];
+
+        for (from_base, to_base) in pairs {
+            let input: Vec<u64> = (0..61).map(|i| (i * 7 + 1) % from_base).collect();
+            assert_eq!(
+                convert_base(&input, from_base, to_base),
+                crate::baseline::convert_base(&input, from_base, to_base),
+                "{} -> {}",
+                from_base,
+                to_base
+            );
+        }
+    }
+
+    #[test]
+    fn test_general_case_unrolled_loops() {
+        // Over 256 bits, so the u128/u256 paths are skipped, but short of
+        // the divide-and-conquer threshold: hits the 4- and 16-way loops
+        for len in [30, 41, 63] {
+            let input: Vec<u64> = (0..len).map(|i| (i * 7 + 3) % 1000).collect();
+            assert!(try_convert_to_u256(&input, 1000).is_none());
+            assert_eq!(convert_base(&input, 1000, 7), crate::baseline::convert_base(&input, 1000, 7));
+        }
+    }
+
+    #[test]
+    fn test_large_number_chunked() {
+        let input: Vec<u64> = (0..2100).map(|i| (i * 7 + 3) % 10).collect();
+        assert_eq!(convert_base(&input, 10, 7), crate::baseline::convert_base(&input, 10, 7));
+    }
+
+    #[test]
+    fn test_general_case() {
+        // Convert between non-aligned bases
+        let input = vec![9, 8, 7];
+        let result = convert_base(&input, 10, 7);
+        // Verify with baseline
+        let expected = crate::baseline::convert_base(&input, 10, 7);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::optimized::convert_base;
+use alloc::vec::Vec;
+
+/// A sign and a magnitude, with the magnitude digits least significant first
+///
+/// The digits carry no base, just as with the plain `convert_base` API.
+/// `new` and `convert_signed` clear the sign when the magnitude is zero, so
+/// `-0` never comes out of the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedNumber {
+    pub negative: bool,
+    pub digits: Vec<u64>,
+}
+
+impl SignedNumber {
+    /// Create a signed number, normalizing `-0` to `+0`
+    pub fn new(negative: bool, digits: Vec<u64>) -> Self {
+        let negative = negative && digits.iter().any(|&d| d != 0);
+        SignedNumber { negative, digits }
+    }
+}
+
+/// Convert the magnitude with `convert_base` and keep the sign
+pub fn convert_signed(n: &SignedNumber, from_base: u64, to_base: u64) -> SignedNumber {
+    SignedNumber::new(n.negative, convert_base(&n.digits, from_base, to_base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_is_preserved() {
+        let n = SignedNumber::new(true, vec![5, 5, 2]);
+        assert_eq!(convert_signed(&n, 10, 16), SignedNumber::new(true, vec![15, 15]));
+
+        let n = SignedNumber::new(false, vec![5, 5, 2]);
+        assert_eq!(convert_signed(&n, 10, 16), SignedNumber::new(false, vec![15, 15]));
+    }
+
+    #[test]
+    fn test_negative_zero_normalizes() {
+        assert!(!SignedNumber::new(true, vec![0]).negative);
+        assert!(!SignedNumber::new(true, vec![]).negative);
+
+        // Built directly, bypassing `new`
+        let n = SignedNumber { negative: true, digits: vec![0, 0] };
+        assert_eq!(convert_signed(&n, 10, 2), SignedNumber { negative: false, digits: vec![0] });
+    }
+}
@@ -0,0 +1,101 @@
+use crate::optimized::Strategy;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// One counter per `Strategy` variant, shared by every thread
+static COUNTERS: [AtomicU64; 9] = [const { AtomicU64::new(0) }; 9];
+
+/// How many conversions took each strategy since the last `take_stats`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConversionStats {
+    pub zero: u64,
+    pub same_base: u64,
+    pub power_of_two: u64,
+    pub u128_fast_path: u64,
+    pub u256_fast_path: u64,
+    pub aligned: u64,
+    pub divide_and_conquer: u64,
+    pub general_chunked: u64,
+    pub general: u64,
+}
+
+impl ConversionStats {
+    /// Conversions counted across all strategies
+    pub fn total(&self) -> u64 {
+        self.zero
+            + self.same_base
+            + self.power_of_two
+            + self.u128_fast_path
+            + self.u256_fast_path
+            + self.aligned
+            + self.divide_and_conquer
+            + self.general_chunked
+            + self.general
+    }
+}
+
+fn counter_index(strategy: Strategy) -> usize {
+    match strategy {
+        Strategy::Zero => 0,
+        Strategy::SameBase => 1,
+        Strategy::PowerOfTwo => 2,
+        Strategy::U128FastPath => 3,
+        Strategy::U256FastPath => 4,
+        Strategy::Aligned { .. } => 5,
+        Strategy::DivideAndConquer => 6,
+        Strategy::GeneralChunked => 7,
+        Strategy::General => 8,
+    }
+}
+
+/// Count one conversion; a single relaxed atomic add
+pub(crate) fn record(strategy: Strategy) {
+    COUNTERS[counter_index(strategy)].fetch_add(1, Ordering::Relaxed);
+}
+
+/// Read the strategy counters and reset them to zero
+///
+/// The counters are process-wide atomics, so this covers conversions on
+/// every thread. Each counter is swapped out on its own, so a conversion
+/// finishing during the call lands in either this snapshot or the next,
+/// never in both.
+pub fn take_stats() -> ConversionStats {
+    let take = |i: usize| COUNTERS[i].swap(0, Ordering::Relaxed);
+    ConversionStats {
+        zero: take(0),
+        same_base: take(1),
+        power_of_two: take(2),
+        u128_fast_path: take(3),
+        u256_fast_path: take(4),
+        aligned: take(5),
+        divide_and_conquer: take(6),
+        general_chunked: take(7),
+        general: take(8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optimized::convert_base;
+
+    #[test]
+    fn test_take_stats_counts_and_resets() {
+        take_stats();
+
+        convert_base(&[0xA, 0xB], 16, 2);
+        convert_base(&[0xA, 0xB], 16, 8);
+        convert_base(&[5, 5, 2], 10, 16);
+        convert_base(&[0], 10, 16);
+        convert_base(&[8; 300], 9, 27);
+        convert_base(&[6; 300], 7, 11);
+
+        // Other tests may convert concurrently, so counts are lower bounds
+        let stats = take_stats();
+        assert!(stats.power_of_two >= 2);
+        assert!(stats.u128_fast_path >= 1);
+        assert!(stats.zero >= 1);
+        assert!(stats.aligned >= 1);
+        assert!(stats.divide_and_conquer >= 1);
+        assert!(stats.total() >= 6);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::error::ConvertError;
+use crate::optimized::try_convert_base;
+use crate::utils::{check_digits, MAX_BASE};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Longest unary output `convert_base_unary_aware` will produce
+pub const MAX_UNARY_LEN: usize = 1 << 20;
+
+/// Like `try_convert_base`, but also accepts base 1 (unary) on either side
+///
+/// In unary a number `n` is `n` copies of the digit 1, so zero is the
+/// empty slice. Converting to unary fails with `OutputTooLarge` when `n`
+/// exceeds `MAX_UNARY_LEN`. The largest regular base is still `MAX_BASE`.
+pub fn convert_base_unary_aware(digits: &[u64], from_base: u64, to_base: u64) -> Result<Vec<u64>, ConvertError> {
+    for base in [from_base, to_base] {
+        if !(1..=MAX_BASE).contains(&base) {
+            return Err(ConvertError::BaseOutOfRange { base });
+        }
+    }
+
+    if from_base == 1 {
+        if let Some(index) = digits.iter().position(|&digit| digit != 1) {
+            return Err(ConvertError::InvalidDigit { digit: digits[index], base: 1, index });
+        }
+        if to_base == 1 {
+            return Ok(digits.to_vec());
+        }
+        return Ok(u64_to_digits(digits.len() as u64, to_base));
+    }
+
+    if to_base != 1 {
+        return try_convert_base(digits, from_base, to_base);
+    }
+
+    check_digits(digits, from_base)?;
+
+    // Anything that overflows u64 is far beyond the cap anyway
+    let too_large = ConvertError::OutputTooLarge { needed: usize::MAX, max: MAX_UNARY_LEN };
+    let mut value = 0u64;
+    for &digit in digits.iter().rev() {
+        value = value
+            .checked_mul(from_base)
+            .and_then(|v| v.checked_add(digit))
+            .ok_or(too_large.clone())?;
+    }
+
+    let needed = usize::try_from(value).map_err(|_| too_large)?;
+    if needed > MAX_UNARY_LEN {
+        return Err(ConvertError::OutputTooLarge { needed, max: MAX_UNARY_LEN });
+    }
+
+    Ok(vec![1; needed])
+}
+
+fn u64_to_digits(mut value: u64, base: u64) -> Vec<u64> {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(value % base);
+        value /= base;
+        if value == 0 {
+            return digits;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unary_round_trip() {
+        assert_eq!(convert_base_unary_aware(&[1, 1, 1, 1, 1], 1, 10), Ok(vec![5]));
+        assert_eq!(convert_base_unary_aware(&[5], 10, 1), Ok(vec![1; 5]));
+        assert_eq!(convert_base_unary_aware(&[2, 1], 10, 1), Ok(vec![1; 12]));
+        assert_eq!(convert_base_unary_aware(&[1; 12], 1, 10), Ok(vec![2, 1]));
+
+        // Zero is the empty tally
+        assert_eq!(convert_base_unary_aware(&[], 1, 10), Ok(vec![0]));
+        assert_eq!(convert_base_unary_aware(&[0], 10, 1), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_non_unary_pairs_forward() {
+        assert_eq!(convert_base_unary_aware(&[5, 5, 2], 10, 16), Ok(vec![15, 15]));
+        assert_eq!(convert_base_unary_aware(&[1], 0, 10), Err(ConvertError::BaseOutOfRange { base: 0 }));
+    }
+
+    #[test]
+    fn test_unary_errors() {
+        assert_eq!(
+            convert_base_unary_aware(&[1, 0, 1], 1, 10),
+            Err(ConvertError::InvalidDigit { digit: 0, base: 1, index: 1 })
+        );
+        assert_eq!(
+            convert_base_unary_aware(&[0, 0, 0, 0, 0, 0, 0, 1], 10, 1),
+            Err(ConvertError::OutputTooLarge { needed: 10_000_000, max: MAX_UNARY_LEN })
+        );
+        assert!(matches!(
+            convert_base_unary_aware(&[9; 30], 10, 1),
+            Err(ConvertError::OutputTooLarge { .. })
+        ));
+    }
+}
@@ -1,4 +1,19 @@
-use std::cmp::Ordering;
+use crate::error::ConvertError;
+use crate::optimized::convert_base;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Largest supported base, 2^32
+///
+/// Conversion steps compute `carry * from_base + digit` with
+/// `carry < to_base` and `digit < from_base`, which is at most
+/// `from_base * to_base - 1`. Capping both bases at 2^32 keeps that within
+/// u64 for every base pair.
+pub const MAX_BASE: u64 = 1 << 32;
 
 /// Check if a number is a power of two
 pub fn is_power_of_two(n: u64) -> bool {
@@ -11,50 +26,663 @@ pub fn log2_of_power_of_two(n: u64) -> u32 {
     n.trailing_zeros()
 }
 
+/// The `r` with `r^k == n`, if `n` is an exact `k`-th power
+///
+/// A binary search over integers with `checked_pow`, so there is no
+/// floating-point rounding to second-guess. `k == 0` has no answer and
+/// gives `None`; `k == 1` gives `n` back.
+pub fn exact_integer_root(n: u64, k: u32) -> Option<u64> {
+    if k == 0 {
+        return None;
+    }
+    if k == 1 || n < 2 {
+        return Some(n);
+    }
+
+    // r < 2^(bits(n) / k + 1), and r^k must not overflow past n
+    let bits = 64 - n.leading_zeros();
+    let mut lo = 1u64;
+    let mut hi = 1u64 << (bits / k + 1).min(63);
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        match mid.checked_pow(k) {
+            Some(power) if power <= n => lo = mid,
+            _ => hi = mid - 1,
+        }
+    }
+
+    (lo.pow(k) == n).then_some(lo)
+}
+
+/// Write `n` as `root^k` with the largest `k >= 2`, if possible
+///
+/// The largest exponent gives the smallest root, which is not itself a
+/// perfect power: 64 is `(2, 6)`, not `(8, 2)`. Numbers below 4 are never
+/// perfect powers in this sense.
+pub fn is_perfect_power(n: u64) -> Option<(u64, u32)> {
+    if n < 4 {
+        return None;
+    }
+
+    let max_exponent = 63 - n.leading_zeros();
+    (2..=max_exponent).rev().find_map(|k| exact_integer_root(n, k).map(|root| (root, k)))
+}
+
+/// Upper bound on the number of output digits for a conversion
+///
+/// An `input_len`-digit number in `from_base` is below `from_base^input_len`,
+/// so it needs at most `input_len * ln(from_base) / ln(to_base)` digits in
+/// `to_base`, rounded up. A small slack absorbs floating-point error.
+/// Useful for `Vec::with_capacity` or rejecting inputs that would be too
+/// large before converting.
+pub fn estimate_output_len(input_len: usize, from_base: u64, to_base: u64) -> usize {
+    if input_len == 0 {
+        return 1;
+    }
+    if from_base == to_base {
+        return input_len;
+    }
+
+    estimate_from_ratio(input_len, from_base, to_base)
+}
+
+#[cfg(feature = "std")]
+fn estimate_from_ratio(input_len: usize, from_base: u64, to_base: u64) -> usize {
+    let ratio = (from_base as f64).ln() / (to_base as f64).ln();
+    (input_len as f64 * ratio).ceil() as usize + 2
+}
+
+/// `f64::ln` needs `std`, so bound the ratio with whole bits instead:
+/// `from_base < 2^ceil(log2 from_base)` and `to_base >= 2^floor(log2 to_base)`.
+/// This overestimates by up to a factor of two for awkward bases.
+#[cfg(not(feature = "std"))]
+fn estimate_from_ratio(input_len: usize, from_base: u64, to_base: u64) -> usize {
+    let from_bits = (64 - (from_base - 1).leading_zeros()) as usize;
+    let to_bits = to_base.ilog2() as usize;
+    (input_len * from_bits).div_ceil(to_bits) + 2
+}
+
+/// Largest base whose digits can all be written as a single symbol
+const MAX_SYMBOL_BASE: u64 = 62;
+
+/// Symbols used by `digits_to_string` and `string_to_digits`
+const DEFAULT_ALPHABET: [char; 62] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
 /// Convert a digit slice to a string representation
-pub fn digits_to_string(digits: &[u64], _base: u64) -> String {
+///
+/// `digits[0]` is the least significant digit and ends up as the last
+/// character, so `[3, 2, 1]` in base 10 is `"123"`; see
+/// `digits_msb_to_string` for digits in reading order. Digits are written with the alphabet `0-9a-zA-Z`: values 0..=9 map to
+/// `0`-`9`, 10..=35 to `a`-`z` and 36..=61 to `A`-`Z`. For bases above 62
+/// every digit is written as bracketed decimal instead, e.g. `[1234]`.
+pub fn digits_to_string(digits: &[u64], base: u64) -> String {
+    if base <= MAX_SYMBOL_BASE {
+        if let Ok(s) = encode_with_alphabet(digits, &DEFAULT_ALPHABET) {
+            return s;
+        }
+    }
+
+    // Zero is still a bracketed digit, so string_to_digits can read it back
     if digits.is_empty() {
-        return "0".to_string();
+        return "[0]".to_string();
     }
 
-    let mut chars = Vec::new();
+    let mut s = String::with_capacity(digits.len() * 3);
     for &digit in digits.iter().rev() {
-        if digit < 10 {
-            chars.push((b'0' + digit as u8) as char);
-        } else if digit < 36 {
-            chars.push((b'a' + (digit - 10) as u8) as char);
-        } else {
-            chars.push('?');
+        s.push_str(&format!("[{}]", digit));
+    }
+    s
+}
+
+/// Convert a digit slice to a string with `sep` between groups of symbols
+///
+/// Groups of `group_size` symbols are counted from the least significant
+/// end, so only the most significant group can be shorter and there is
+/// never a leading separator: `[5, 4, 3, 2, 1]` in base 10 with groups of
+/// 3 is `"12_345"`. A bracketed digit such as `[1234]` counts as one
+/// symbol. A `group_size` of 0 disables grouping.
+pub fn digits_to_string_grouped(digits: &[u64], base: u64, group_size: usize, sep: char) -> String {
+    let s = digits_to_string(digits, base);
+    if group_size == 0 {
+        return s;
+    }
+
+    let symbols: Vec<&str> = if s.starts_with('[') {
+        s.split_inclusive(']').collect()
+    } else {
+        s.char_indices().map(|(i, c)| &s[i..i + c.len_utf8()]).collect()
+    };
+
+    let mut grouped = String::with_capacity(s.len() + symbols.len() / group_size);
+    for (i, symbol) in symbols.iter().enumerate() {
+        if i > 0 && (symbols.len() - i).is_multiple_of(group_size) {
+            grouped.push(sep);
         }
+        grouped.push_str(symbol);
+    }
+    grouped
+}
+
+/// Largest power of ten that is a valid base, used as one decimal chunk
+const DECIMAL_CHUNK: u64 = 1_000_000_000;
+
+/// Write a number in `from_base` as a decimal string
+///
+/// Same result as `digits_to_string(&convert_base(digits, from_base, 10), 10)`,
+/// but the conversion targets base 10^9 and each limb is formatted as nine
+/// decimal digits, so it produces a ninth as many limbs.
+pub fn to_decimal_string(digits: &[u64], from_base: u64) -> String {
+    let chunks = convert_base(digits, from_base, DECIMAL_CHUNK);
+
+    let mut s = String::with_capacity(chunks.len() * 9);
+    let mut chunks = chunks.iter().rev();
+    if let Some(top) = chunks.next() {
+        s.push_str(&top.to_string());
+    }
+    for chunk in chunks {
+        s.push_str(&format!("{:09}", chunk));
+    }
+    s
+}
+
+/// Convert a digit slice to a string using uppercase letters
+///
+/// Useful for hashes and checksums that are conventionally written as
+/// `FF` rather than `ff`. Only bases up to 36 are affected; above that
+/// letter case is significant and the output matches `digits_to_string`.
+pub fn digits_to_string_upper(digits: &[u64], base: u64) -> String {
+    let s = digits_to_string(digits, base);
+    if base <= 36 {
+        s.to_ascii_uppercase()
+    } else {
+        s
     }
-    chars.into_iter().collect()
 }
 
 /// Parse a string into digits in the given base
+///
+/// For bases up to 36 letters are case-insensitive. For bases 37..=62
+/// lowercase letters are 10..=35 and uppercase letters are 36..=61, the
+/// same ordering `digits_to_string` emits. For bases above 62 the string
+/// must consist of bracketed decimal digits such as `[1][999]`.
+///
+/// The returned digits are least significant first, the order
+/// `convert_base` expects, so they are the reverse of the text: `"123"`
+/// gives `[3, 2, 1]`. Use `string_to_digits_msb_first` to keep reading
+/// order.
 pub fn string_to_digits(s: &str, base: u64) -> Result<Vec<u64>, String> {
-    if s.is_empty() {
-        return Ok(vec![0]);
+    if base > MAX_SYMBOL_BASE {
+        return bracketed_to_digits(s, base).map_err(|err| match err {
+            ConvertError::InvalidDigit { digit, .. } => {
+                format!("Digit '{}' out of range for base {}", digit, base)
+            }
+            _ => format!("Invalid bracketed digit in: {}", s),
+        });
+    }
+
+    parse_default_symbols(s, base).map_err(|err| match err {
+        ConvertError::InvalidCharacter { character } if default_symbol_value(character).is_some() => {
+            format!("Digit '{}' out of range for base {}", character, base)
+        }
+        other => other.to_string(),
+    })
+}
+
+/// `string_to_digits`, but with the digits in reading order
+///
+/// `digits[0]` is the leftmost character, so `"123"` gives `[1, 2, 3]`.
+/// Reverse the result, or use `convert_base_be`, before converting.
+pub fn string_to_digits_msb_first(s: &str, base: u64) -> Result<Vec<u64>, String> {
+    let mut digits = string_to_digits(s, base)?;
+    digits.reverse();
+    Ok(digits)
+}
+
+/// `digits_to_string` for digits in reading order, most significant first
+///
+/// The inverse of `string_to_digits_msb_first`: `[1, 2, 3]` in base 10 is
+/// `"123"`.
+pub fn digits_msb_to_string(digits: &[u64], base: u64) -> String {
+    let little_endian: Vec<u64> = digits.iter().rev().copied().collect();
+    digits_to_string(&little_endian, base)
+}
+
+/// Convert a string in `from_base` straight to a string in `to_base`
+///
+/// Chains `string_to_digits`, `convert_base` and `digits_to_string`, so
+/// both sides use the same symbols, including the bracketed form above
+/// base 62. The empty string is zero and converts to `"0"`. Unsupported
+/// bases, unknown characters and out-of-range digits come back as
+/// `ConvertError` instead of panicking.
+///
+/// ```
+/// use fast_base_convert::convert_str;
+///
+/// assert_eq!(convert_str("deadbeef", 16, 10).unwrap(), "3735928559");
+/// ```
+pub fn convert_str(s: &str, from_base: u64, to_base: u64) -> Result<String, ConvertError> {
+    for base in [from_base, to_base] {
+        if !(2..=MAX_BASE).contains(&base) {
+            return Err(ConvertError::BaseOutOfRange { base });
+        }
+    }
+
+    let digits = if from_base > MAX_SYMBOL_BASE {
+        bracketed_to_digits(s, from_base)?
+    } else {
+        parse_default_symbols(s, from_base)?
+    };
+
+    Ok(digits_to_string(&convert_base(&digits, from_base, to_base), to_base))
+}
+
+/// Convert a user-typed number string, the whole pipeline a CLI needs
+///
+/// Separators from `DEFAULT_SEPARATORS` are dropped first, then an optional
+/// `0x`/`0b`/`0o` prefix is stripped, and the rest goes through the same
+/// parsing and formatting as `convert_str`. A prefix takes precedence over
+/// `from_base`, so `"0xff"` is read as hex whatever `from_base` says. In
+/// bases above 11 that makes a leading `0b` a prefix rather than two
+/// digits; write such numbers without the leading zero. A string with
+/// nothing left after the separators and prefix is `EmptyInput`, while the
+/// empty string itself is zero, as in `convert_str`.
+///
+/// ```
+/// use fast_base_convert::convert_auto_str;
+///
+/// assert_eq!(convert_auto_str("0xff", 10, 10).unwrap(), "255");
+/// assert_eq!(convert_auto_str("1_000", 10, 2).unwrap(), "1111101000");
+/// ```
+pub fn convert_auto_str(input: &str, from_base: u64, to_base: u64) -> Result<String, ConvertError> {
+    let (body, from_base) = strip_auto_input(input, from_base)?;
+    if from_base > MAX_SYMBOL_BASE || to_base > MAX_SYMBOL_BASE {
+        return convert_str(&body, from_base, to_base);
+    }
+
+    let body = if from_base <= 36 { body.to_ascii_lowercase() } else { body };
+    convert_stripped_with_alphabet(&body, from_base, to_base, &DEFAULT_ALPHABET)
+}
+
+/// `convert_auto_str` with a custom alphabet for both input and output
+///
+/// Separators and a radix prefix are stripped exactly as in
+/// `convert_auto_str`, so the alphabet should not use `_`, `,` or spaces.
+/// The input is then parsed with `alphabet[..from_base]` and the result
+/// formatted with `alphabet[..to_base]`, matching symbols exactly. A base
+/// larger than the alphabet is `BaseOutOfRange`.
+///
+/// ```
+/// use fast_base_convert::convert_auto_str_with_alphabet;
+///
+/// let alphabet: Vec<char> = "0123456789ABCDEF".chars().collect();
+/// assert_eq!(convert_auto_str_with_alphabet("255", 10, 16, &alphabet).unwrap(), "FF");
+/// assert_eq!(convert_auto_str_with_alphabet("0xFF", 10, 2, &alphabet).unwrap(), "11111111");
+/// ```
+pub fn convert_auto_str_with_alphabet(
+    input: &str,
+    from_base: u64,
+    to_base: u64,
+    alphabet: &[char],
+) -> Result<String, ConvertError> {
+    validate_alphabet(alphabet)?;
+    let (body, from_base) = strip_auto_input(input, from_base)?;
+    convert_stripped_with_alphabet(&body, from_base, to_base, alphabet)
+}
+
+/// Parse an already-stripped body with `alphabet` and format it back
+///
+/// `body` has had its separators and radix prefix removed, so it is not
+/// stripped again: `"0x0b1"` reaches here as `"0b1"` in base 16.
+fn convert_stripped_with_alphabet(
+    body: &str,
+    from_base: u64,
+    to_base: u64,
+    alphabet: &[char],
+) -> Result<String, ConvertError> {
+    for base in [from_base, to_base] {
+        if !(2..=alphabet.len() as u64).contains(&base) {
+            return Err(ConvertError::BaseOutOfRange { base });
+        }
+    }
+
+    let digits = string_to_digits_with_alphabet(body, &alphabet[..from_base as usize])?;
+    digits_to_string_with_alphabet(&convert_base(&digits, from_base, to_base), &alphabet[..to_base as usize])
+}
+
+/// Drop separators and a radix prefix, returning the body and its base
+fn strip_auto_input(input: &str, from_base: u64) -> Result<(String, u64), ConvertError> {
+    let cleaned: String = input.chars().filter(|c| !DEFAULT_SEPARATORS.contains(c)).collect();
+    let (body, from_base) = match split_radix_prefix(&cleaned) {
+        (body, base) if body.len() < cleaned.len() => (body.to_string(), base),
+        _ => (cleaned.clone(), from_base),
+    };
+    if body.is_empty() && !input.is_empty() {
+        return Err(ConvertError::EmptyInput);
+    }
+    Ok((body, from_base))
+}
+
+/// Parse a string that may carry a radix prefix, returning digits and base
+///
+/// A leading `0x`/`0X` selects base 16, `0b`/`0B` base 2 and `0o`/`0O`
+/// base 8. Without a prefix the string is parsed as base 10.
+pub fn string_to_digits_auto(s: &str) -> Result<(Vec<u64>, u64), ConvertError> {
+    let (body, base) = split_radix_prefix(s);
+    let digits = parse_default_symbols(body, base)?;
+    Ok((digits, base))
+}
+
+/// Separators skipped by `string_to_digits_lenient` by default
+pub const DEFAULT_SEPARATORS: [char; 3] = ['_', ',', ' '];
+
+/// Parse a string into digits, ignoring digit-group separators
+///
+/// Every character in `separators` is dropped before parsing, so
+/// `"1_000_000"` and `"1,234,567"` parse as plain decimal numbers when
+/// given `&DEFAULT_SEPARATORS`. Separators may appear anywhere, including
+/// at either end, but a non-empty string made only of separators is an
+/// error. Digits use the same `0-9a-zA-Z` symbols as `string_to_digits`.
+pub fn string_to_digits_lenient(s: &str, base: u64, separators: &[char]) -> Result<Vec<u64>, ConvertError> {
+    let cleaned: String = s.chars().filter(|c| !separators.contains(c)).collect();
+    if cleaned.is_empty() && !s.is_empty() {
+        return Err(ConvertError::EmptyInput);
+    }
+    parse_default_symbols(&cleaned, base)
+}
+
+/// Parse a string into digits, accepting decimal digits from any script
+///
+/// Like `string_to_digits` for bases up to 62, but a digit 0-9 may also be
+/// written with a Unicode decimal digit such as full-width `１` or
+/// Arabic-Indic `١`. Letters are still the ASCII `a-zA-Z` symbols. The
+/// error for an unknown or out-of-range character carries the character as
+/// written, not its ASCII equivalent.
+pub fn string_to_digits_unicode(s: &str, base: u64) -> Result<Vec<u64>, ConvertError> {
+    if !(2..=MAX_SYMBOL_BASE).contains(&base) {
+        return Err(ConvertError::BaseOutOfRange { base });
+    }
+
+    decode_with(s, |c| {
+        let value = match unicode_decimal_value(c) {
+            Some(digit) => Some(digit),
+            None if base <= 36 => default_symbol_value(c.to_ascii_lowercase()),
+            None => default_symbol_value(c),
+        };
+        value.filter(|&digit| digit < base)
+    })
+}
+
+/// First code point of each run of ten Unicode decimal digits (`Nd`, Unicode 14)
+const UNICODE_DIGIT_ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6,
+    0x0B66, 0x0BE6, 0x0C66, 0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0,
+    0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900,
+    0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x10D30, 0x11066,
+    0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650, 0x116C0,
+    0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0,
+    0x1E950, 0x1FBF0,
+];
+
+/// The value of a Unicode decimal digit, or `None` for anything else
+fn unicode_decimal_value(c: char) -> Option<u64> {
+    let code = c as u32;
+    let run = match UNICODE_DIGIT_ZEROS.binary_search(&code) {
+        Ok(i) => i,
+        Err(0) => return None,
+        Err(i) => i - 1,
+    };
+    let offset = code - UNICODE_DIGIT_ZEROS[run];
+    (offset < 10).then_some(offset as u64)
+}
+
+/// Parse digits from a reader without holding the text in memory
+///
+/// Bytes are read through the `BufRead` buffer and each symbol becomes a
+/// digit as soon as it is seen, using the same `0-9a-zA-Z` symbols as
+/// `string_to_digits` (so only bases up to 62). Only the digit vector is
+/// built, and it is reversed once at the end. A trailing line ending is
+/// ignored; any other whitespace, or a symbol after the line ending, is an
+/// `InvalidCharacterAt` carrying its byte offset. Read failures come back as
+/// `ConvertError::Io`, and a non-ASCII byte is reported as U+FFFD.
+#[cfg(feature = "std")]
+pub fn parse_digits_from_reader<R: std::io::BufRead>(mut reader: R, base: u64) -> Result<Vec<u64>, ConvertError> {
+    if !(2..=MAX_SYMBOL_BASE).contains(&base) {
+        return Err(ConvertError::BaseOutOfRange { base });
     }
 
     let mut digits = Vec::new();
-    for c in s.chars().rev() {
-        let digit = if c.is_ascii_digit() {
-            c as u64 - '0' as u64
-        } else if c.is_ascii_lowercase() {
-            c as u64 - 'a' as u64 + 10
-        } else if c.is_ascii_uppercase() {
-            c as u64 - 'A' as u64 + 10
-        } else {
-            return Err(format!("Invalid character: {}", c));
+    let mut seen_line_end = false;
+    let mut position = 0;
+
+    loop {
+        let chunk = match reader.fill_buf() {
+            Ok(chunk) => chunk,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(ConvertError::Io { message: e.to_string() }),
+        };
+        if chunk.is_empty() {
+            break;
+        }
+
+        for &byte in chunk {
+            let c = if byte.is_ascii() { byte as char } else { char::REPLACEMENT_CHARACTER };
+            if c == '\n' || c == '\r' {
+                seen_line_end = true;
+                position += 1;
+                continue;
+            }
+
+            let digit = if base <= 36 { c.to_ascii_lowercase() } else { c };
+            match default_symbol_value(digit).filter(|&digit| digit < base && !seen_line_end) {
+                Some(digit) => digits.push(digit),
+                None => return Err(ConvertError::InvalidCharacterAt { character: c, position }),
+            }
+            position += 1;
+        }
+
+        let len = chunk.len();
+        reader.consume(len);
+    }
+
+    digits.reverse();
+    digits.truncate(significant_len(&digits).max(1));
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    Ok(digits)
+}
+
+/// Strip a `0x`, `0b` or `0o` prefix and report the base it implies
+fn split_radix_prefix(s: &str) -> (&str, u64) {
+    let bytes = s.as_bytes();
+    if bytes.len() >= 2 && bytes[0] == b'0' {
+        let base = match bytes[1] {
+            b'x' | b'X' => Some(16),
+            b'b' | b'B' => Some(2),
+            b'o' | b'O' => Some(8),
+            _ => None,
         };
+        if let Some(base) = base {
+            return (&s[2..], base);
+        }
+    }
+    (s, 10)
+}
+
+/// Convert a digit slice to a string using a custom alphabet
+///
+/// The alphabet's length is the base and `alphabet[i]` is the symbol for
+/// digit value `i`, so `['0', '1']` produces binary output.
+pub fn digits_to_string_with_alphabet(digits: &[u64], alphabet: &[char]) -> Result<String, ConvertError> {
+    validate_alphabet(alphabet)?;
+    encode_with_alphabet(digits, alphabet)
+}
+
+/// Convert digits and write the result to `w` through a custom alphabet
+///
+/// Symbols go out most significant first, encoded as UTF-8 through a small
+/// stack buffer, so the output `String` is never built. The converted digit
+/// vector is still computed in full. `alphabet[i]` is the symbol for digit
+/// `i` and must cover every digit of `to_base`; an alphabet that is too
+/// short or repeats a symbol is an `InvalidInput` error. Invalid input
+/// digits panic, as with `convert_base`.
+#[cfg(feature = "std")]
+pub fn write_converted<W: std::io::Write>(
+    w: &mut W,
+    digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    alphabet: &[char],
+) -> std::io::Result<()> {
+    let invalid = |e: ConvertError| std::io::Error::new(std::io::ErrorKind::InvalidInput, e);
+    validate_alphabet(alphabet).map_err(invalid)?;
+    if (alphabet.len() as u64) < to_base {
+        return Err(invalid(ConvertError::BaseOutOfRange { base: to_base }));
+    }
+
+    let converted = convert_base(digits, from_base, to_base);
+
+    let mut buf = [0u8; 1024];
+    let mut len = 0;
+    for &digit in converted.iter().rev() {
+        if len + 4 > buf.len() {
+            w.write_all(&buf[..len])?;
+            len = 0;
+        }
+        len += alphabet[digit as usize].encode_utf8(&mut buf[len..]).len();
+    }
+    w.write_all(&buf[..len])
+}
+
+/// Parse a string into digits using a custom alphabet
+///
+/// The inverse of `digits_to_string_with_alphabet`. Symbols are matched
+/// exactly, so an alphabet that should be case-insensitive must be
+/// normalized by the caller.
+pub fn string_to_digits_with_alphabet(s: &str, alphabet: &[char]) -> Result<Vec<u64>, ConvertError> {
+    validate_alphabet(alphabet)?;
+
+    let values: BTreeMap<char, u64> = alphabet
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| (c, i as u64))
+        .collect();
+
+    decode_with(s, |c| values.get(&c).copied())
+}
+
+/// Check that an alphabet is non-empty and has no repeated symbols
+fn validate_alphabet(alphabet: &[char]) -> Result<(), ConvertError> {
+    if alphabet.is_empty() {
+        return Err(ConvertError::EmptyAlphabet);
+    }
+
+    let mut seen = BTreeSet::new();
+    for &symbol in alphabet {
+        if !seen.insert(symbol) {
+            return Err(ConvertError::DuplicateSymbol { symbol });
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse with the default alphabet, case-insensitively for bases up to 36
+pub(crate) fn parse_default_symbols(s: &str, base: u64) -> Result<Vec<u64>, ConvertError> {
+    decode_with(s, |c| {
+        let c = if base <= 36 { c.to_ascii_lowercase() } else { c };
+        default_symbol_value(c).filter(|&digit| digit < base)
+    })
+}
+
+/// Look up a character in the default `0-9a-zA-Z` alphabet
+fn default_symbol_value(c: char) -> Option<u64> {
+    if c.is_ascii_digit() {
+        Some(c as u64 - '0' as u64)
+    } else if c.is_ascii_lowercase() {
+        Some(c as u64 - 'a' as u64 + 10)
+    } else if c.is_ascii_uppercase() {
+        Some(c as u64 - 'A' as u64 + 36)
+    } else {
+        None
+    }
+}
+
+fn encode_with_alphabet(digits: &[u64], alphabet: &[char]) -> Result<String, ConvertError> {
+    let base = alphabet.len() as u64;
+
+    if digits.is_empty() {
+        return Ok(alphabet[0].to_string());
+    }
 
+    let mut s = String::with_capacity(digits.len());
+    for (index, &digit) in digits.iter().enumerate().rev() {
         if digit >= base {
-            return Err(format!("Digit '{}' out of range for base {}", c, base));
+            return Err(ConvertError::InvalidDigit { digit, base, index });
+        }
+        s.push(alphabet[digit as usize]);
+    }
+    Ok(s)
+}
+
+fn decode_with(s: &str, symbol_value: impl Fn(char) -> Option<u64>) -> Result<Vec<u64>, ConvertError> {
+    if s.is_empty() {
+        return Ok(vec![0]);
+    }
+
+    let mut digits = Vec::with_capacity(s.len());
+    for c in s.chars().rev() {
+        match symbol_value(c) {
+            Some(digit) => digits.push(digit),
+            None => return Err(ConvertError::InvalidCharacter { character: c }),
         }
+    }
+
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    Ok(digits)
+}
+
+/// Parse the bracketed decimal form used for bases above 62
+///
+/// A malformed group is reported as `InvalidCharacter` with the first
+/// character that doesn't fit the `[digits]` shape.
+fn bracketed_to_digits(s: &str, base: u64) -> Result<Vec<u64>, ConvertError> {
+    let mut digits = Vec::new();
+    let mut rest = s;
+
+    while !rest.is_empty() {
+        let inner = rest
+            .strip_prefix('[')
+            .and_then(|r| r.find(']').map(|end| (&r[..end], &r[end + 1..])));
+        let (group, tail) = match inner {
+            Some(parts) => parts,
+            None => {
+                let character = if rest.starts_with('[') { '[' } else { rest.chars().next().unwrap() };
+                return Err(ConvertError::InvalidCharacter { character });
+            }
+        };
+
+        let digit: u64 = group.parse().map_err(|_| ConvertError::InvalidCharacter {
+            character: group.chars().find(|c| !c.is_ascii_digit()).unwrap_or(']'),
+        })?;
 
         digits.push(digit);
+        rest = tail;
     }
 
+    digits.reverse();
+    check_digits(&digits, base)?;
     while digits.len() > 1 && digits.last() == Some(&0) {
         digits.pop();
     }
@@ -62,6 +690,32 @@ pub fn string_to_digits(s: &str, base: u64) -> Result<Vec<u64>, String> {
     Ok(digits)
 }
 
+/// Treat a little-endian byte buffer as a base-256 number
+///
+/// Each byte becomes one digit, least significant first. Zero bytes at the
+/// most significant end are trimmed, and an empty buffer is zero (`[0]`).
+pub fn digits_from_bytes(bytes: &[u8]) -> Vec<u64> {
+    let mut digits: Vec<u64> = bytes.iter().map(|&b| b as u64).collect();
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        digits.push(0);
+    }
+    digits
+}
+
+/// Convert a number to base 256 and pack it into little-endian bytes
+///
+/// The inverse of `digits_from_bytes`: the output has no zero bytes at
+/// the most significant end, except that zero is encoded as `[0]`.
+pub fn digits_to_bytes(digits: &[u64], base: u64) -> Vec<u8> {
+    convert_base(digits, base, 256)
+        .into_iter()
+        .map(|d| d as u8)
+        .collect()
+}
+
 /// Compare two numbers represented as digit arrays
 pub fn compare_digits(a: &[u64], b: &[u64]) -> Ordering {
     let a_trimmed = trim_leading_zeros(a);
@@ -81,6 +735,142 @@ pub fn compare_digits(a: &[u64], b: &[u64]) -> Ordering {
     Ordering::Equal
 }
 
+/// Compare a number in `base` with a plain `u64`
+///
+/// `value` is written out in `base` on the stack (at most 64 digits, for
+/// base 2) and compared with `compare_digits`. A number with more
+/// significant digits than `value` could ever need is `Greater` without
+/// looking at `value` at all. Panics if `base` is out of range.
+pub fn compare_to_u64(digits: &[u64], base: u64, value: u64) -> Ordering {
+    if !(2..=MAX_BASE).contains(&base) {
+        panic!("Bases must be between 2 and {}", MAX_BASE);
+    }
+    let significant = significant_len(digits);
+    if significant > 64 {
+        return Ordering::Greater;
+    }
+
+    let mut value_digits = [0u64; 64];
+    let mut len = 0;
+    let mut rest = value;
+    while rest > 0 {
+        value_digits[len] = rest % base;
+        rest /= base;
+        len += 1;
+    }
+
+    // Both sides without leading zeros, so every form of zero is equal
+    compare_digits(&digits[..significant], &value_digits[..len])
+}
+
+/// Whether two digit arrays in the same base hold the same number
+///
+/// Zero limbs at the most significant end are ignored, and every form of
+/// zero is equal, including `[]` and `[0]` (which `compare_digits` keeps
+/// apart, since it trims down to a lone digit).
+pub fn digits_equal(a: &[u64], b: &[u64]) -> bool {
+    a[..significant_len(a)] == b[..significant_len(b)]
+}
+
+/// Compare two numbers written in different bases
+///
+/// `b` is converted to `base_a` unless the bases already match. Both
+/// sides are then compared without their leading zeros, as in
+/// `digits_equal`, so every form of zero is equal.
+pub fn compare_across_bases(a: &[u64], base_a: u64, b: &[u64], base_b: u64) -> Ordering {
+    let a = &a[..significant_len(a)];
+    if base_a == base_b {
+        return compare_digits(a, &b[..significant_len(b)]);
+    }
+
+    let b = convert_base(b, base_b, base_a);
+    compare_digits(a, &b[..significant_len(&b)])
+}
+
+/// Convert to `to_base` and back, and check that the value survived
+///
+/// Both directions use `convert_base`, and the result is compared with
+/// `digits_equal`, so leading zeros in the input don't count as a
+/// mismatch. This costs two full conversions and is meant for fuzz
+/// harnesses and test suites, not hot paths.
+pub fn assert_round_trip(digits: &[u64], from_base: u64, to_base: u64) -> bool {
+    convert_and_verify(digits, from_base, to_base).1
+}
+
+/// Convert, and report whether converting back gives the input again
+///
+/// Returns the forward result of `convert_base` together with the check
+/// `assert_round_trip` makes, so a caller can show the output and flag a
+/// mismatch from one call. The comparison uses `digits_equal` rather than
+/// `compare_digits`, so leading zeros in the input, including every form
+/// of zero, are not a mismatch.
+pub fn convert_and_verify(digits: &[u64], from_base: u64, to_base: u64) -> (Vec<u64>, bool) {
+    verify_with(digits, from_base, to_base, convert_base)
+}
+
+fn verify_with(
+    digits: &[u64],
+    from_base: u64,
+    to_base: u64,
+    convert: impl Fn(&[u64], u64, u64) -> Vec<u64>,
+) -> (Vec<u64>, bool) {
+    let there = convert(digits, from_base, to_base);
+    let back = convert(&there, to_base, from_base);
+    let ok = digits_equal(digits, &back);
+    (there, ok)
+}
+
+/// Check whether a digit array represents zero
+///
+/// True for `[]`, `[0]`, `[0, 0]` and so on. Scans from the most
+/// significant end, so a trimmed non-zero number is rejected immediately.
+pub fn is_zero(digits: &[u64]) -> bool {
+    digits.iter().rev().all(|&d| d == 0)
+}
+
+/// Check that `base` is supported and every digit is below it
+///
+/// The checks `convert_base` makes before converting, reported as errors
+/// instead of panics. The first digit out of range, counting from the
+/// least significant end, comes back as `InvalidDigit` with its `index`
+/// in the slice. An empty slice is valid; it is zero.
+///
+/// ```
+/// use fast_base_convert::{validate_digits, ConvertError};
+///
+/// assert_eq!(validate_digits(&[1, 0, 1], 2), Ok(()));
+/// assert_eq!(
+///     validate_digits(&[1, 2, 1], 2),
+///     Err(ConvertError::InvalidDigit { digit: 2, base: 2, index: 1 })
+/// );
+/// ```
+pub fn validate_digits(digits: &[u64], base: u64) -> Result<(), ConvertError> {
+    if !(2..=MAX_BASE).contains(&base) {
+        return Err(ConvertError::BaseOutOfRange { base });
+    }
+    check_digits(digits, base)
+}
+
+/// Whether `validate_digits` would succeed
+pub fn digits_valid(digits: &[u64], base: u64) -> bool {
+    (2..=MAX_BASE).contains(&base) && digits.iter().all(|&digit| digit < base)
+}
+
+/// The digit half of `validate_digits`, for callers that checked the base
+pub(crate) fn check_digits(digits: &[u64], base: u64) -> Result<(), ConvertError> {
+    match digits.iter().position(|&digit| digit >= base) {
+        Some(index) => Err(ConvertError::InvalidDigit { digit: digits[index], base, index }),
+        None => Ok(()),
+    }
+}
+
+/// Number of digits up to and including the most significant non-zero one
+///
+/// Zero in any form has a significant length of 0.
+pub fn significant_len(digits: &[u64]) -> usize {
+    digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1)
+}
+
 /// Remove leading zeros from digit array
 pub fn trim_leading_zeros(digits: &[u64]) -> &[u64] {
     if digits.is_empty() {
@@ -118,6 +908,31 @@ mod tests {
         assert_eq!(log2_of_power_of_two(16), 4);
     }
 
+    #[test]
+    fn test_exact_integer_root() {
+        assert_eq!(exact_integer_root(1000, 3), Some(10));
+        assert_eq!(exact_integer_root(1001, 3), None);
+        assert_eq!(exact_integer_root(u64::MAX, 1), Some(u64::MAX));
+        assert_eq!(exact_integer_root(u64::MAX, 2), None);
+        assert_eq!(exact_integer_root(1 << 63, 63), Some(2));
+        assert_eq!(exact_integer_root(4_294_967_295u64 * 4_294_967_295, 2), Some(4_294_967_295));
+        assert_eq!(exact_integer_root(0, 5), Some(0));
+        assert_eq!(exact_integer_root(1, 5), Some(1));
+        assert_eq!(exact_integer_root(8, 0), None);
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        assert_eq!(is_perfect_power(8), Some((2, 3)));
+        assert_eq!(is_perfect_power(64), Some((2, 6)));
+        assert_eq!(is_perfect_power(216), Some((6, 3)));
+        assert_eq!(is_perfect_power(3_600_000_000), Some((60000, 2)));
+        assert_eq!(is_perfect_power(1 << 63), Some((2, 63)));
+        assert_eq!(is_perfect_power(12), None);
+        assert_eq!(is_perfect_power(u64::MAX), None);
+        assert_eq!(is_perfect_power(3), None);
+    }
+
     #[test]
     fn test_digits_to_string() {
         let digits = vec![1, 0, 1, 1]; // 1101 in binary
@@ -136,6 +951,419 @@ mod tests {
         assert_eq!(digits, vec![15, 15]);
     }
 
+    #[test]
+    fn test_validate_digits() {
+        assert_eq!(validate_digits(&[9, 0, 9], 10), Ok(()));
+        assert!(digits_valid(&[9, 0, 9], 10));
+
+        assert_eq!(
+            validate_digits(&[9, 10, 11], 10),
+            Err(ConvertError::InvalidDigit { digit: 10, base: 10, index: 1 })
+        );
+        assert!(!digits_valid(&[9, 10, 11], 10));
+        assert_eq!(
+            validate_digits(&[MAX_BASE], MAX_BASE),
+            Err(ConvertError::InvalidDigit { digit: MAX_BASE, base: MAX_BASE, index: 0 })
+        );
+
+        assert_eq!(validate_digits(&[], 10), Ok(()));
+        assert!(digits_valid(&[], 10));
+
+        assert_eq!(validate_digits(&[0], 1), Err(ConvertError::BaseOutOfRange { base: 1 }));
+        assert!(!digits_valid(&[], MAX_BASE + 1));
+
+        // The index counts from the least significant digit, the last group
+        assert_eq!(
+            convert_str("[1000][1]", 1000, 10),
+            Err(ConvertError::InvalidDigit { digit: 1000, base: 1000, index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_msb_first_ordering() {
+        assert_eq!(string_to_digits("123", 10).unwrap(), vec![3, 2, 1]);
+        assert_eq!(string_to_digits_msb_first("123", 10).unwrap(), vec![1, 2, 3]);
+
+        assert_eq!(digits_to_string(&[3, 2, 1], 10), "123");
+        assert_eq!(digits_msb_to_string(&[1, 2, 3], 10), "123");
+        assert_eq!(digits_msb_to_string(&[1, 999], 1000), "[1][999]");
+
+        assert!(string_to_digits_msb_first("12a", 10).is_err());
+    }
+
+    #[test]
+    fn test_digits_to_string_upper() {
+        let s = digits_to_string_upper(&[15, 15], 16);
+        assert_eq!(s, "FF");
+        assert_eq!(string_to_digits(&s, 16).unwrap(), vec![15, 15]);
+
+        // Case is significant above base 36
+        assert_eq!(digits_to_string_upper(&[10, 36], 62), digits_to_string(&[10, 36], 62));
+    }
+
+    #[test]
+    fn test_digits_to_string_grouped() {
+        let byte = string_to_digits("10101010", 2).unwrap();
+        assert_eq!(digits_to_string_grouped(&byte, 2, 4, '_'), "1010_1010");
+        assert_eq!(digits_to_string_grouped(&[1, 0, 1, 0, 1], 2, 4, '_'), "1_0101");
+
+        assert_eq!(digits_to_string_grouped(&[7, 6, 5, 4, 3, 2, 1], 10, 3, ','), "1,234,567");
+        assert_eq!(digits_to_string_grouped(&[6, 5, 4, 3, 2, 1], 10, 3, ','), "123,456");
+        assert_eq!(digits_to_string_grouped(&[1], 10, 3, ','), "1");
+
+        assert_eq!(digits_to_string_grouped(&[15, 15, 15, 15], 16, 2, '_'), "ff_ff");
+        assert_eq!(digits_to_string_grouped(&[3, 2, 1], 1000, 2, ' '), "[1] [2][3]");
+        assert_eq!(digits_to_string_grouped(&[5, 5, 2], 10, 0, '_'), "255");
+    }
+
+    #[test]
+    fn test_base62_round_trip() {
+        assert_eq!(digits_to_string(&[61], 62), "Z");
+        assert_eq!(digits_to_string(&[36, 35], 62), "zA");
+        assert_eq!(string_to_digits("Z", 62).unwrap(), vec![61]);
+        assert_eq!(string_to_digits("zA", 62).unwrap(), vec![36, 35]);
+    }
+
+    #[test]
+    fn test_bracketed_round_trip() {
+        let s = digits_to_string(&[1000], 2000);
+        assert_eq!(s, "[1000]");
+        assert_eq!(string_to_digits(&s, 2000).unwrap(), vec![1000]);
+
+        let s = digits_to_string(&[5, 1999, 1], 2000);
+        assert_eq!(s, "[1][1999][5]");
+        assert_eq!(string_to_digits(&s, 2000).unwrap(), vec![5, 1999, 1]);
+
+        let s = digits_to_string(&[], 2000);
+        assert_eq!(s, "[0]");
+        assert_eq!(string_to_digits(&s, 2000).unwrap(), vec![0]);
+        assert_eq!(digits_to_string(&[], 10), "0");
+
+        assert!(string_to_digits("[2000]", 2000).is_err());
+        assert!(string_to_digits("[12", 2000).is_err());
+    }
+
+    #[test]
+    fn test_to_decimal_string() {
+        assert_eq!(to_decimal_string(&[0xF, 0xF, 0xF, 0xF], 16), "65535");
+        assert_eq!(to_decimal_string(&[0xFF, 0xFF], 256), "65535");
+        assert_eq!(to_decimal_string(&[], 2), "0");
+        // Inner chunks keep their zeros: 10^18 + 1
+        let input = string_to_digits("1000000000000000001", 10).unwrap();
+        assert_eq!(to_decimal_string(&convert_base(&input, 10, 7), 7), "1000000000000000001");
+
+        let long: Vec<u64> = (0..500).map(|i| (i * 7 + 3) % 16).collect();
+        assert_eq!(to_decimal_string(&long, 16), digits_to_string(&convert_base(&long, 16, 10), 10));
+    }
+
+    #[test]
+    fn test_parse_digits_from_reader() {
+        use std::io::{BufReader, Cursor};
+
+        let text: String = (0..100_000u32).map(|i| char::from(b'0' + ((i * 7 + 3) % 10) as u8)).collect();
+        // A tiny buffer makes the symbols span many fill_buf calls
+        let reader = BufReader::with_capacity(7, Cursor::new(text.clone()));
+        assert_eq!(parse_digits_from_reader(reader, 10).unwrap(), string_to_digits(&text, 10).unwrap());
+
+        assert_eq!(parse_digits_from_reader(Cursor::new("FF\r\n"), 16).unwrap(), vec![15, 15]);
+        assert_eq!(parse_digits_from_reader(Cursor::new(""), 10).unwrap(), vec![0]);
+        assert_eq!(parse_digits_from_reader(Cursor::new("0012"), 10).unwrap(), vec![2, 1]);
+        assert_eq!(
+            parse_digits_from_reader(Cursor::new("12\n3"), 10),
+            Err(ConvertError::InvalidCharacterAt { character: '3', position: 3 })
+        );
+        assert_eq!(
+            parse_digits_from_reader(Cursor::new("19"), 8),
+            Err(ConvertError::InvalidCharacterAt { character: '9', position: 1 })
+        );
+    }
+
+    #[test]
+    fn test_parse_digits_from_reader_reports_offset() {
+        use std::io::{BufReader, Cursor};
+
+        // The offset counts bytes across buffer refills and past the line ending
+        let reader = BufReader::with_capacity(3, Cursor::new("1234567\r\nX"));
+        assert_eq!(
+            parse_digits_from_reader(reader, 10),
+            Err(ConvertError::InvalidCharacterAt { character: 'X', position: 9 })
+        );
+        assert_eq!(
+            parse_digits_from_reader(Cursor::new("ab cd"), 16),
+            Err(ConvertError::InvalidCharacterAt { character: ' ', position: 2 })
+        );
+        let err = parse_digits_from_reader(Cursor::new("7\nz"), 10).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid character: z at byte 2");
+    }
+
+    #[test]
+    fn test_parse_digits_from_reader_io_error() {
+        struct Failing;
+        impl std::io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk on fire"))
+            }
+        }
+
+        let err = parse_digits_from_reader(std::io::BufReader::new(Failing), 10).unwrap_err();
+        assert_eq!(err, ConvertError::Io { message: "disk on fire".into() });
+        assert_eq!(err.to_string(), "I/O error: disk on fire");
+    }
+
+    #[test]
+    fn test_write_converted() {
+        let hex: Vec<char> = "0123456789abcdef".chars().collect();
+        let input: Vec<u64> = (0..3000).map(|i| (i * 7 + 1) % 10).collect();
+
+        let mut out = Vec::new();
+        write_converted(&mut out, &input, 10, 16, &hex).unwrap();
+        let expected = digits_to_string(&convert_base(&input, 10, 16), 16);
+        assert!(expected.len() > 1024);
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+        // Multi-byte symbols straddle the internal buffer boundary
+        let greek: Vec<char> = "αβ".chars().collect();
+        let mut out = Vec::new();
+        write_converted(&mut out, &[0, 1, 0, 1, 1, 0, 1], 2, 2, &greek).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "βαββαβα");
+        let mut out = Vec::new();
+        write_converted(&mut out, &[1; 700], 2, 2, &greek).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "β".repeat(700));
+
+        let mut out = Vec::new();
+        write_converted(&mut out, &[], 10, 16, &hex).unwrap();
+        assert_eq!(out, b"0");
+
+        let err = write_converted(&mut Vec::new(), &[5], 10, 16, &hex[..10]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_convert_str() {
+        assert_eq!(convert_str("deadbeef", 16, 10).unwrap(), "3735928559");
+        assert_eq!(convert_str("DEADBEEF", 16, 2).unwrap(), "11011110101011011011111011101111");
+        assert_eq!(convert_str("", 10, 2).unwrap(), "0");
+        assert_eq!(convert_str("[1][0]", 1000, 10).unwrap(), "1000");
+        assert_eq!(convert_str("255", 10, 1000).unwrap(), "[255]");
+
+        assert_eq!(convert_str("12g", 16, 10), Err(ConvertError::InvalidCharacter { character: 'g' }));
+        assert_eq!(convert_str("[1000]", 1000, 10), Err(ConvertError::InvalidDigit { digit: 1000, base: 1000, index: 0 }));
+        assert_eq!(convert_str("[12", 1000, 10), Err(ConvertError::InvalidCharacter { character: '[' }));
+        assert_eq!(convert_str("10", 10, 1), Err(ConvertError::BaseOutOfRange { base: 1 }));
+    }
+
+    #[test]
+    fn test_convert_auto_str() {
+        assert_eq!(convert_auto_str("0xff", 10, 10).unwrap(), "255");
+        assert_eq!(convert_auto_str("0xff", 2, 10).unwrap(), "255");
+        assert_eq!(convert_auto_str("1_000", 10, 2).unwrap(), "1111101000");
+        assert_eq!(convert_auto_str(" 0b1111_0000 ", 10, 16).unwrap(), "f0");
+        assert_eq!(convert_auto_str("[1], [0]", 1000, 10).unwrap(), "1000");
+        assert_eq!(convert_auto_str("", 10, 2).unwrap(), "0");
+
+        assert_eq!(convert_auto_str("0x", 10, 2), Err(ConvertError::EmptyInput));
+        assert_eq!(convert_auto_str("__", 10, 2), Err(ConvertError::EmptyInput));
+        assert_eq!(convert_auto_str("0b12", 10, 2), Err(ConvertError::InvalidCharacter { character: '2' }));
+        assert_eq!(convert_auto_str("12", 1, 2), Err(ConvertError::BaseOutOfRange { base: 1 }));
+        // Only one prefix is stripped; the rest is read in the prefix's base
+        assert_eq!(convert_auto_str("0x0b1", 10, 10).unwrap(), "177");
+        assert_eq!(convert_auto_str("0x0o7", 10, 10), Err(ConvertError::InvalidCharacter { character: 'o' }));
+    }
+
+    #[test]
+    fn test_convert_auto_str_with_alphabet() {
+        let upper: Vec<char> = "0123456789ABCDEF".chars().collect();
+        assert_eq!(convert_auto_str_with_alphabet("0xFF", 10, 10, &upper).unwrap(), "255");
+        assert_eq!(convert_auto_str_with_alphabet("65_535", 10, 16, &upper).unwrap(), "FFFF");
+        assert_eq!(convert_auto_str_with_alphabet("0x0B1", 10, 10, &upper).unwrap(), "177");
+        // Symbols match exactly, so lowercase hex is not in this alphabet
+        assert_eq!(
+            convert_auto_str_with_alphabet("0xff", 10, 10, &upper),
+            Err(ConvertError::InvalidCharacter { character: 'f' })
+        );
+
+        let dna = ['A', 'C', 'G', 'T'];
+        assert_eq!(convert_auto_str_with_alphabet("TTT", 4, 4, &dna).unwrap(), "TTT");
+        assert_eq!(convert_auto_str_with_alphabet("CA", 4, 2, &dna).unwrap(), "CAA");
+        assert_eq!(convert_auto_str_with_alphabet("", 4, 2, &dna).unwrap(), "A");
+        assert_eq!(convert_auto_str_with_alphabet("0x1", 4, 4, &dna), Err(ConvertError::BaseOutOfRange { base: 16 }));
+        assert_eq!(
+            convert_auto_str_with_alphabet("A", 4, 4, &['A', 'A']),
+            Err(ConvertError::DuplicateSymbol { symbol: 'A' })
+        );
+    }
+
+    #[test]
+    fn test_string_to_digits_unicode() {
+        assert_eq!(string_to_digits_unicode("１２３", 10).unwrap(), vec![3, 2, 1]);
+        assert_eq!(string_to_digits_unicode("١٢٣", 10).unwrap(), vec![3, 2, 1]);
+        assert_eq!(string_to_digits_unicode("१०", 10).unwrap(), vec![0, 1]);
+        assert_eq!(string_to_digits_unicode("１f", 16).unwrap(), vec![15, 1]);
+        assert_eq!(string_to_digits_unicode("123", 10).unwrap(), vec![3, 2, 1]);
+
+        assert_eq!(string_to_digits_unicode("１９", 8), Err(ConvertError::InvalidCharacter { character: '９' }));
+        assert_eq!(string_to_digits_unicode("½", 10), Err(ConvertError::InvalidCharacter { character: '½' }));
+        assert_eq!(string_to_digits_unicode("1", 100), Err(ConvertError::BaseOutOfRange { base: 100 }));
+    }
+
+    #[test]
+    fn test_string_to_digits_auto() {
+        assert_eq!(string_to_digits_auto("0xFF").unwrap(), (vec![15, 15], 16));
+        assert_eq!(string_to_digits_auto("0b1010").unwrap(), (vec![0, 1, 0, 1], 2));
+        assert_eq!(string_to_digits_auto("0o17").unwrap(), (vec![7, 1], 8));
+        assert_eq!(string_to_digits_auto("123").unwrap(), (vec![3, 2, 1], 10));
+        assert_eq!(string_to_digits_auto("0").unwrap(), (vec![0], 10));
+        assert_eq!(
+            string_to_digits_auto("0b102"),
+            Err(ConvertError::InvalidCharacter { character: '2' })
+        );
+    }
+
+    #[test]
+    fn test_string_to_digits_lenient() {
+        let digits = string_to_digits_lenient("1_000_000", 10, &DEFAULT_SEPARATORS).unwrap();
+        assert_eq!(digits, string_to_digits("1000000", 10).unwrap());
+
+        let digits = string_to_digits_lenient(" 1,234,567_", 10, &DEFAULT_SEPARATORS).unwrap();
+        assert_eq!(digits, vec![7, 6, 5, 4, 3, 2, 1]);
+
+        let digits = string_to_digits_lenient("ff:ff", 16, &[':']).unwrap();
+        assert_eq!(digits, vec![15, 15, 15, 15]);
+
+        assert_eq!(
+            string_to_digits_lenient("_, _", 10, &DEFAULT_SEPARATORS),
+            Err(ConvertError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_is_zero_and_significant_len() {
+        for zero in [&[][..], &[0], &[0, 0], &[0, 0, 0]] {
+            assert!(is_zero(zero));
+            assert_eq!(significant_len(zero), 0);
+        }
+
+        assert!(!is_zero(&[0, 1]));
+        assert_eq!(significant_len(&[0, 1]), 2);
+        assert_eq!(significant_len(&[5, 0, 0]), 1);
+        assert_eq!(significant_len(&[1, 0, 2, 0]), 3);
+    }
+
+    #[test]
+    fn test_compare_to_u64() {
+        assert_eq!(compare_to_u64(&[5, 5, 2], 10, 255), Ordering::Equal);
+        assert_eq!(compare_to_u64(&[5, 5, 2, 0], 10, 256), Ordering::Less);
+        assert_eq!(compare_to_u64(&[5, 5, 2], 10, 254), Ordering::Greater);
+        // 1000 needs one more digit than the array has
+        assert_eq!(compare_to_u64(&[9, 9, 9], 10, 1000), Ordering::Less);
+        assert_eq!(compare_to_u64(&[0, 0, 0, 1], 10, 999), Ordering::Greater);
+
+        assert_eq!(compare_to_u64(&[], 10, 0), Ordering::Equal);
+        assert_eq!(compare_to_u64(&[0, 0], 10, 0), Ordering::Equal);
+        assert_eq!(compare_to_u64(&[], 10, 1), Ordering::Less);
+        assert_eq!(compare_to_u64(&[1; 64], 2, u64::MAX), Ordering::Equal);
+        assert_eq!(compare_to_u64(&[1; 65], 2, u64::MAX), Ordering::Greater);
+        assert_eq!(compare_to_u64(&[u32::MAX as u64; 2], 1 << 32, u64::MAX), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_compare_across_bases() {
+        assert_eq!(compare_across_bases(&[5], 10, &[1, 0, 1], 2), Ordering::Equal);
+        assert_eq!(compare_across_bases(&[5, 5, 2], 10, &[0, 0, 1], 16), Ordering::Less);
+        assert_eq!(compare_across_bases(&[0, 0, 1], 16, &[5, 5, 2], 10), Ordering::Greater);
+        assert_eq!(compare_across_bases(&[3, 0], 10, &[3], 10), Ordering::Equal);
+        assert_eq!(compare_across_bases(&[], 10, &[0], 2), Ordering::Equal);
+        assert_eq!(compare_across_bases(&[], 10, &[0], 10), Ordering::Equal);
+        assert_eq!(compare_across_bases(&[0, 0], 10, &[], 16), Ordering::Equal);
+        assert_eq!(compare_across_bases(&[5, 0, 0], 10, &[1, 0, 1, 0], 2), Ordering::Equal);
+        assert_eq!(compare_across_bases(&[0, 0], 10, &[1], 2), Ordering::Less);
+        assert_eq!(compare_across_bases(&[1, 0, 0], 10, &[0, 0], 2), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_assert_round_trip() {
+        assert!(assert_round_trip(&[5, 5, 2], 10, 16));
+        assert!(assert_round_trip(&[5, 5, 2, 0, 0], 10, 7));
+        assert!(assert_round_trip(&[], 2, 65536));
+        let long: Vec<u64> = (0..300).map(|i| (i * 37 + 11) % 1000).collect();
+        assert!(assert_round_trip(&long, 1000, 3));
+    }
+
+    #[test]
+    fn test_convert_and_verify() {
+        assert_eq!(convert_and_verify(&[5, 5, 2], 10, 16), (vec![15, 15], true));
+        assert_eq!(convert_and_verify(&[5, 5, 2, 0, 0], 10, 7), (convert_base(&[5, 5, 2], 10, 7), true));
+        assert_eq!(convert_and_verify(&[], 10, 2), (vec![0], true));
+
+        // A converter that drops the top digit on the way back is caught
+        let lossy = |digits: &[u64], from_base: u64, to_base: u64| {
+            let mut result = convert_base(digits, from_base, to_base);
+            if to_base == 10 && result.len() > 1 {
+                result.pop();
+            }
+            result
+        };
+        assert_eq!(verify_with(&[5, 5, 2], 10, 16, lossy), (vec![15, 15], false));
+        assert_eq!(verify_with(&[5], 10, 16, lossy), (vec![5], true));
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let crockford: Vec<char> = "0123456789ABCDEFGHJKMNPQRSTVWXYZ".chars().collect();
+        let s = digits_to_string_with_alphabet(&[31, 18], &crockford).unwrap();
+        assert_eq!(s, "JZ");
+        assert_eq!(string_to_digits_with_alphabet(&s, &crockford).unwrap(), vec![31, 18]);
+
+        assert_eq!(
+            string_to_digits_with_alphabet("JI", &crockford),
+            Err(ConvertError::InvalidCharacter { character: 'I' })
+        );
+        assert_eq!(
+            digits_to_string_with_alphabet(&[32], &crockford),
+            Err(ConvertError::InvalidDigit { digit: 32, base: 32, index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_invalid_alphabet() {
+        assert_eq!(
+            digits_to_string_with_alphabet(&[0], &[]),
+            Err(ConvertError::EmptyAlphabet)
+        );
+        assert_eq!(
+            string_to_digits_with_alphabet("a", &['a', 'b', 'a']),
+            Err(ConvertError::DuplicateSymbol { symbol: 'a' })
+        );
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        assert_eq!(digits_from_bytes(&[]), vec![0]);
+        assert_eq!(digits_to_bytes(&digits_from_bytes(&[]), 256), vec![0]);
+
+        assert_eq!(digits_from_bytes(&[0]), vec![0]);
+        assert_eq!(digits_to_bytes(&[0], 10), vec![0]);
+
+        // The zero top byte is trimmed on the way in and not restored
+        assert_eq!(digits_from_bytes(&[0x34, 0x12, 0x00]), vec![0x34, 0x12]);
+        assert_eq!(digits_to_bytes(&digits_from_bytes(&[0x34, 0x12, 0x00]), 256), vec![0x34, 0x12]);
+
+        // 4660 = 0x1234
+        assert_eq!(digits_to_bytes(&[0, 6, 6, 4], 10), vec![0x34, 0x12]);
+    }
+
+    #[test]
+    fn test_digits_equal() {
+        assert!(digits_equal(&[], &[0]));
+        assert!(digits_equal(&[0, 0], &[]));
+        assert!(digits_equal(&[5], &[5, 0]));
+        assert!(digits_equal(&[1, 2, 3], &[1, 2, 3]));
+
+        assert!(!digits_equal(&[1, 2, 3], &[1, 2]));
+        assert!(!digits_equal(&[0, 5], &[5]));
+        assert!(!digits_equal(&[], &[1]));
+    }
+
     #[test]
     fn test_compare_digits() {
         let a = vec![1, 2, 3]; // 321
@@ -1,6 +1,6 @@
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
-use crate::{convert_base, convert_base_baseline};
+use crate::{convert_base, convert_base_baseline, digits_to_string, string_to_digits, try_convert_base};
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
@@ -51,8 +51,30 @@ pub struct BenchmarkRunner {
     config: BenchmarkConfig,
 }
 
+/// Pre-canned inputs for the named benchmark scenarios
+///
+/// Returns the digits and the base pair, or `None` for a custom run.
+fn scenario(test_type: &str) -> Option<(Vec<u64>, u64, u64)> {
+    match test_type {
+        // 4000 bits, repacked by the power-of-two path
+        "power_of_two" => Some(((0..1000).map(|i| (i * 7 + 3) % 16).collect(), 16, 2)),
+        // 1000 decimal digits, past the divide-and-conquer threshold
+        "large" => Some(((0..1000).map(|i| (i * 7 + 3) % 10).collect(), 10, 16)),
+        // 9^3 = 27^2, so groups of three digits map to two
+        "aligned" => Some(((0..600).map(|i| (i * 7 + 3) % 9).collect(), 9, 27)),
+        _ => None,
+    }
+}
+
 #[wasm_bindgen]
 impl BenchmarkRunner {
+    /// Set up a benchmark run
+    ///
+    /// `test_type` picks the input. `"power_of_two"` (1000 hex digits to
+    /// base 2), `"large"` (1000 decimal digits to base 16) and `"aligned"`
+    /// (600 base-9 digits to base 27) use pre-canned inputs and ignore
+    /// `input_digits`, `from_base` and `to_base`. Any other value, such as
+    /// `"custom"`, benchmarks the digits and bases passed in.
     #[wasm_bindgen(constructor)]
     pub fn new(
         test_type: String,
@@ -61,16 +83,19 @@ impl BenchmarkRunner {
         from_base: u32,
         to_base: u32,
     ) -> BenchmarkRunner {
-        // Convert Vec<u32> to Vec<u64> for WASM compatibility
-        let input_digits_64: Vec<u64> = input_digits.into_iter().map(|x| x as u64).collect();
+        let (input_digits, from_base, to_base) = scenario(&test_type).unwrap_or_else(|| {
+            // Convert Vec<u32> to Vec<u64> for WASM compatibility
+            let input_digits_64: Vec<u64> = input_digits.into_iter().map(|x| x as u64).collect();
+            (input_digits_64, from_base as u64, to_base as u64)
+        });
 
         BenchmarkRunner {
             config: BenchmarkConfig {
                 test_type,
                 iterations,
-                input_digits: input_digits_64,
-                from_base: from_base as u64,
-                to_base: to_base as u64,
+                input_digits,
+                from_base,
+                to_base,
             },
         }
     }
@@ -137,6 +162,61 @@ impl BenchmarkRunner {
     }
 }
 
+/// Convert a number between bases
+///
+/// Digits cross the JS boundary as u32, like `BenchmarkRunner::new`, and
+/// are least significant first. Any u32 base from 2 up is in range.
+#[wasm_bindgen]
+pub fn wasm_convert(digits: Vec<u32>, from_base: u32, to_base: u32) -> Vec<u32> {
+    let digits: Vec<u64> = digits.into_iter().map(|x| x as u64).collect();
+    convert_base(&digits, from_base as u64, to_base as u64)
+        .into_iter()
+        .map(|x| x as u32)
+        .collect()
+}
+
+/// Like `wasm_convert`, but invalid bases or digits throw a catchable JS
+/// error instead of panicking and aborting the instance
+#[wasm_bindgen]
+pub fn wasm_try_convert(digits: Vec<u32>, from_base: u32, to_base: u32) -> Result<Vec<u32>, JsValue> {
+    let digits: Vec<u64> = digits.into_iter().map(|x| x as u64).collect();
+    try_convert_base(&digits, from_base as u64, to_base as u64)
+        .map(|result| result.into_iter().map(|x| x as u32).collect())
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Convert many numbers in one call to amortize the JS boundary cost
+///
+/// `inputs` is an array of digit arrays, each least significant first,
+/// e.g. `[[5, 5, 2], [0, 1], [7]]`. The result is an array of the
+/// converted digit arrays in the same order. A malformed array or an
+/// invalid digit throws a JS error.
+#[wasm_bindgen]
+pub fn wasm_convert_batch(inputs: JsValue, from_base: u32, to_base: u32) -> JsValue {
+    let inputs: Vec<Vec<u64>> =
+        serde_wasm_bindgen::from_value(inputs).unwrap_or_else(|e| wasm_bindgen::throw_str(&e.to_string()));
+
+    let results: Vec<Vec<u64>> = inputs
+        .iter()
+        .map(|digits| {
+            try_convert_base(digits, from_base as u64, to_base as u64)
+                .unwrap_or_else(|e| wasm_bindgen::throw_str(&e.to_string()))
+        })
+        .collect();
+
+    serde_wasm_bindgen::to_value(&results).unwrap()
+}
+
+/// Convert a number written as a string, e.g. `"ff"` in base 16
+///
+/// Parsing and formatting follow `string_to_digits` / `digits_to_string`.
+/// A string that doesn't parse throws a JS exception with the message.
+#[wasm_bindgen]
+pub fn wasm_convert_str(s: &str, from_base: u32, to_base: u32) -> String {
+    let digits = string_to_digits(s, from_base as u64).unwrap_or_else(|e| wasm_bindgen::throw_str(&e));
+    digits_to_string(&convert_base(&digits, from_base as u64, to_base as u64), to_base as u64)
+}
+
 #[wasm_bindgen]
 pub fn run_quick_benchmark(
     test_type: String,
@@ -153,4 +233,44 @@ pub fn run_quick_benchmark(
 #[wasm_bindgen(start)]
 pub fn main() {
     console_error_panic_hook::set_once();
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_wasm_convert() {
+        assert_eq!(wasm_convert(vec![5, 5, 2], 10, 16), vec![15, 15]);
+        assert_eq!(wasm_convert_str("255", 10, 16), "ff");
+        assert_eq!(wasm_convert_str("ff", 16, 2), "11111111");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_try_convert_reports_errors() {
+        assert_eq!(wasm_try_convert(vec![5, 5, 2], 10, 16).unwrap(), vec![15, 15]);
+
+        let err = wasm_try_convert(vec![10], 10, 16).unwrap_err();
+        assert_eq!(err.as_string().unwrap(), "Invalid digit 10 for base 10");
+        assert!(wasm_try_convert(vec![1], 1, 16).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_benchmark_scenarios() {
+        let runner = BenchmarkRunner::new("aligned".into(), 1, vec![1], 10, 2);
+        assert_eq!((runner.config.from_base, runner.config.to_base), (9, 27));
+        assert_eq!(runner.config.input_digits.len(), 600);
+
+        let runner = BenchmarkRunner::new("custom".into(), 1, vec![5, 5, 2], 10, 16);
+        assert_eq!(runner.config.input_digits, vec![5, 5, 2]);
+        assert_eq!((runner.config.from_base, runner.config.to_base), (10, 16));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_convert_batch() {
+        let inputs = serde_wasm_bindgen::to_value(&vec![vec![5u64, 5, 2], vec![0, 1], vec![0]]).unwrap();
+        let results: Vec<Vec<u64>> = serde_wasm_bindgen::from_value(wasm_convert_batch(inputs, 10, 16)).unwrap();
+        assert_eq!(results, vec![vec![15, 15], vec![10], vec![0]]);
+    }
+}
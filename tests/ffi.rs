@@ -0,0 +1,42 @@
+//! Drives the C interface the way a C caller would, with `malloc`ed buffers
+#![cfg(all(feature = "cffi", target_os = "linux"))]
+
+use fast_base_convert::ffi::{fbc_convert, fbc_estimate_len, FBC_ERR_BUFFER_TOO_SMALL, FBC_OK};
+use fast_base_convert::convert_base;
+
+unsafe fn convert_with_malloc(digits: &[u64], from_base: u64, to_base: u64) -> Vec<u64> {
+    let cap = fbc_estimate_len(digits.len(), from_base, to_base);
+    let out = libc::malloc(cap * core::mem::size_of::<u64>()) as *mut u64;
+    assert!(!out.is_null());
+
+    let mut out_len: libc::size_t = 0;
+    let code = fbc_convert(digits.as_ptr(), digits.len(), from_base, to_base, out, cap, &mut out_len);
+    assert_eq!(code, FBC_OK);
+
+    let result = core::slice::from_raw_parts(out, out_len).to_vec();
+    libc::free(out as *mut libc::c_void);
+    result
+}
+
+#[test]
+fn test_ffi_matches_convert_base() {
+    let digits: Vec<u64> = (0..500).map(|i| (i * 7 + 3) % 10).collect();
+    for (from_base, to_base) in [(10, 2), (10, 16), (10, 1000), (16, 2), (9, 27), (10, 10)] {
+        let input = convert_base(&digits, 10, from_base);
+        let result = unsafe { convert_with_malloc(&input, from_base, to_base) };
+        assert_eq!(result, convert_base(&input, from_base, to_base));
+    }
+}
+
+#[test]
+fn test_ffi_retry_after_too_small() {
+    let digits = [9u64; 40];
+    let mut out_len: libc::size_t = 0;
+    let code = unsafe { fbc_convert(digits.as_ptr(), digits.len(), 10, 2, core::ptr::null_mut(), 0, &mut out_len) };
+    assert_eq!(code, FBC_ERR_BUFFER_TOO_SMALL);
+
+    let mut out = vec![0u64; out_len];
+    let code = unsafe { fbc_convert(digits.as_ptr(), digits.len(), 10, 2, out.as_mut_ptr(), out.len(), &mut out_len) };
+    assert_eq!(code, FBC_OK);
+    assert_eq!(out, convert_base(&digits, 10, 2));
+}
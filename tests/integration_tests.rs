@@ -1,6 +1,6 @@
 //! Integration tests for the fast_base_convert library
 
-use fast_base_convert::{convert_base_baseline, convert_base_auto};
+use fast_base_convert::{convert_base, convert_base_baseline, convert_base_auto, divide_conquer, estimate_output_len};
 use num_bigint::BigUint;
 use num_traits::identities::Zero;
 
@@ -98,6 +98,7 @@ fn test_random_conversions() {
 }
 
 #[test]
+#[allow(clippy::useless_vec)]
 fn test_large_numbers() {
     let test_cases = vec![
         // Powers of 10
@@ -152,6 +153,59 @@ fn test_edge_cases() {
     }
 }
 
+#[test]
+fn test_bases_above_65536() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let bases = [100_000, 1_000_000, 1 << 32];
+
+    for &from_base in &bases {
+        for &to_base in [10, 65536, 100_000, 1_000_000, 1 << 32].iter() {
+            // Lengths cover the u128, u256, aligned and divide-and-conquer paths
+            for len in [1, 5, 20, 70, 300] {
+                let digits: Vec<u64> = (0..len).map(|_| rng.gen_range(0..from_base)).collect();
+                let expected = biguint_to_digits(digits_to_biguint(&digits, from_base), to_base);
+
+                verify_conversion(&digits, from_base, to_base);
+                assert_eq!(convert_base(&digits, from_base, to_base), expected);
+                assert_eq!(divide_conquer::convert_base(&digits, from_base, to_base), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_large_base_pair_carry() {
+    // The carry step reaches from_base * to_base - 1, close to u64::MAX for
+    // the largest bases
+    let pairs = [(60000, 50000), (50000, 60000), (1 << 32, (1 << 32) - 1), ((1 << 32) - 1, 1 << 32)];
+
+    for (from_base, to_base) in pairs {
+        for len in [1, 3, 10, 40, 100] {
+            let digits: Vec<u64> = (0..len).map(|i| from_base - 1 - (i * 7919) % 13).collect();
+            let expected = biguint_to_digits(digits_to_biguint(&digits, from_base), to_base);
+
+            verify_conversion(&digits, from_base, to_base);
+            assert_eq!(convert_base(&digits, from_base, to_base), expected);
+        }
+    }
+}
+
+#[test]
+fn test_zero_representations() {
+    let zeros: [&[u64]; 4] = [&[], &[0], &[0, 0], &[0, 0, 0]];
+    let base_pairs = [(10, 2), (2, 16), (16, 8), (10, 10), (6, 36), (65536, 3)];
+
+    for zero in zeros {
+        for (from_base, to_base) in base_pairs {
+            assert_eq!(convert_base(zero, from_base, to_base), vec![0], "{:?} {} -> {}", zero, from_base, to_base);
+            assert_eq!(convert_base_baseline(zero, from_base, to_base), vec![0]);
+            assert_eq!(divide_conquer::convert_base(zero, from_base, to_base), vec![0]);
+        }
+    }
+}
+
 #[test]
 fn test_consistency_between_implementations() {
     let test_cases = vec![
@@ -185,4 +239,34 @@ fn test_round_trip_conversions() {
             "Round trip failed for base {}: {:?} -> {:?} -> {:?}",
             intermediate_base, original_digits, intermediate, recovered);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_estimate_output_len_is_upper_bound() {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..200 {
+        let from_base = rng.gen_range(2..=65536);
+        let to_base = rng.gen_range(2..=65536);
+        let num_digits = rng.gen_range(1..=60);
+
+        // Worst case for the length: every digit at its maximum
+        let mut digits = vec![from_base - 1; num_digits];
+        let converted = convert_base_auto(&digits, from_base, to_base);
+        assert!(
+            estimate_output_len(num_digits, from_base, to_base) >= converted.len(),
+            "{} digits base {} -> base {} needs {}",
+            num_digits, from_base, to_base, converted.len()
+        );
+
+        for digit in digits.iter_mut() {
+            *digit = rng.gen_range(0..from_base);
+        }
+        let converted = convert_base_auto(&digits, from_base, to_base);
+        assert!(estimate_output_len(num_digits, from_base, to_base) >= converted.len());
+    }
+
+    assert_eq!(estimate_output_len(0, 10, 2), 1);
+}